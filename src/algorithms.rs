@@ -1,5 +1,11 @@
+pub mod boolean;
+pub mod concave_hull;
 pub mod convex_hull;
+pub mod convex_hull_3d;
+pub mod convex_layers;
 pub mod intersection;
+pub mod metrics;
+pub mod offset;
 pub mod polygonization;
 pub mod triangulation;
 pub mod visibility;
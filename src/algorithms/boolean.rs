@@ -0,0 +1,660 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::data::{EndPoint, ILineSegment, Line, LineSegmentView, Point, PointLocation, Polygon};
+use crate::{Intersects, Orientation, PolygonScalar};
+
+///////////////////////////////////////////////////////////////////////////////
+// Boolean operations on simple polygons
+
+/// Union of two simple polygons.
+///
+/// Both boundaries are split at their intersection points and the pieces that
+/// lie outside the other polygon are stitched together into new rings.
+/// Disjoint inputs are returned as two separate polygons and a polygon nested
+/// inside the other disappears. Any holes in the union are stored as
+/// clockwise rings.
+///
+/// Crossing points are computed with the scalar type. Use a field such as
+/// [`BigRational`](num::BigRational) for exact results; integer coordinates
+/// will be rounded when edges cross at non-integer points.
+///
+/// # Panics
+///
+/// Panics if either polygon has holes.
+///
+/// # Time complexity
+///
+/// $O(nm(n+m))$
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// # use rgeometry::algorithms::boolean;
+/// # fn main() -> Result<(), rgeometry::Error> {
+/// let a = Polygon::new(vec![
+///   Point::new([0, 0]),
+///   Point::new([2, 0]),
+///   Point::new([2, 2]),
+///   Point::new([0, 2]),
+/// ])?;
+/// let b = Polygon::new(vec![
+///   Point::new([1, 1]),
+///   Point::new([3, 1]),
+///   Point::new([3, 3]),
+///   Point::new([1, 3]),
+/// ])?;
+/// let union = boolean::union(&a, &b);
+/// assert_eq!(union.len(), 1);
+/// assert_eq!(union[0].signed_area_2x::<i32>(), 14);
+/// # Ok(())
+/// # }
+/// ```
+pub fn union<T>(a: &Polygon<T>, b: &Polygon<T>) -> Vec<Polygon<T>>
+where
+  T: PolygonScalar,
+{
+  Overlay::new(a, b).polygons(Operation::Union)
+}
+
+/// Difference of two simple polygons, `a` minus `b`.
+///
+/// Uses the same boundary splitting as [`union`]: pieces of `a` outside `b`
+/// are kept together with the pieces of `b` inside `a`, the latter in reverse
+/// order. If `b` lies strictly inside `a` then the result is `a` with `b` as
+/// a clockwise hole. If `b` covers `a` then the result is empty.
+///
+/// Crossing points are computed with the scalar type. Use a field such as
+/// [`BigRational`](num::BigRational) for exact results; integer coordinates
+/// will be rounded when edges cross at non-integer points.
+///
+/// # Panics
+///
+/// Panics if either polygon has holes.
+///
+/// # Time complexity
+///
+/// $O(nm(n+m))$
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// # use rgeometry::algorithms::boolean;
+/// # fn main() -> Result<(), rgeometry::Error> {
+/// let a = Polygon::new(vec![
+///   Point::new([0, 0]),
+///   Point::new([2, 0]),
+///   Point::new([2, 2]),
+///   Point::new([0, 2]),
+/// ])?;
+/// let b = Polygon::new(vec![
+///   Point::new([1, 1]),
+///   Point::new([3, 1]),
+///   Point::new([3, 3]),
+///   Point::new([1, 3]),
+/// ])?;
+/// let difference = boolean::difference(&a, &b);
+/// assert_eq!(difference.len(), 1);
+/// assert_eq!(difference[0].signed_area_2x::<i32>(), 6);
+/// # Ok(())
+/// # }
+/// ```
+pub fn difference<T>(a: &Polygon<T>, b: &Polygon<T>) -> Vec<Polygon<T>>
+where
+  T: PolygonScalar,
+{
+  Overlay::new(a, b).polygons(Operation::Difference)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operation {
+  Union,
+  Difference,
+  // Both 'a - b' and 'b - a'. The edges are never linked into polygons since
+  // the two parts may touch at shared vertices.
+  SymmetricDifference,
+}
+
+// Edges of 'a - b' and 'b - a' are treated the same way: the outside stays and
+// the inside is reversed. Edges shared in the same direction cancel out.
+fn symmetric_difference(class: EdgeClass) -> Option<bool> {
+  match class {
+    EdgeClass::Outside | EdgeClass::SharedOpposite => Some(true),
+    EdgeClass::Inside => Some(false),
+    EdgeClass::Shared => None,
+  }
+}
+
+// Location of a piece of a boundary relative to the other polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EdgeClass {
+  Inside,
+  Outside,
+  // Overlaps an edge of the other polygon pointing in the same direction.
+  Shared,
+  // Overlaps an edge of the other polygon pointing in the opposite direction.
+  SharedOpposite,
+}
+
+// The boundaries of two polygons split at every point where they meet. Each
+// piece of a boundary is classified against the other polygon.
+pub(crate) struct Overlay<T> {
+  a: Vec<(Point<T>, Point<T>, EdgeClass)>,
+  b: Vec<(Point<T>, Point<T>, EdgeClass)>,
+  // Vertices of the original polygons. Any other vertex was created by
+  // splitting an edge.
+  originals: BTreeSet<Point<T>>,
+}
+
+impl<T: PolygonScalar> Overlay<T> {
+  pub(crate) fn new(a: &Polygon<T>, b: &Polygon<T>) -> Overlay<T> {
+    assert_eq!(a.rings.len(), 1, "boolean operations do not support holes");
+    assert_eq!(b.rings.len(), 1, "boolean operations do not support holes");
+    let ring_a: Vec<Point<T>> = a.iter_boundary().map(|c| c.point().clone()).collect();
+    let ring_b: Vec<Point<T>> = b.iter_boundary().map(|c| c.point().clone()).collect();
+    let (ring_a, ring_b) = split_rings(&ring_a, &ring_b);
+    let originals = a.iter().chain(b.iter()).cloned().collect();
+    Overlay {
+      a: classify(&ring_a, &ring_b, b),
+      b: classify(&ring_b, &ring_a, a),
+      originals,
+    }
+  }
+
+  // Directed edges that make up the boundary of the result.
+  pub(crate) fn edges(&self, op: Operation) -> Vec<(Point<T>, Point<T>)> {
+    // Some(true) keeps an edge as is, Some(false) keeps it reversed.
+    let keep_a = |class: EdgeClass| match op {
+      Operation::Union => matches!(class, EdgeClass::Outside | EdgeClass::Shared).then_some(true),
+      Operation::Difference => {
+        matches!(class, EdgeClass::Outside | EdgeClass::SharedOpposite).then_some(true)
+      }
+      Operation::SymmetricDifference => symmetric_difference(class),
+    };
+    let keep_b = |class: EdgeClass| match op {
+      Operation::Union => (class == EdgeClass::Outside).then_some(true),
+      Operation::Difference => (class == EdgeClass::Inside).then_some(false),
+      Operation::SymmetricDifference => symmetric_difference(class),
+    };
+    let mut edges = Vec::new();
+    for (src, dst, class) in &self.a {
+      match keep_a(*class) {
+        Some(true) => edges.push((src.clone(), dst.clone())),
+        Some(false) => edges.push((dst.clone(), src.clone())),
+        None => {}
+      }
+    }
+    for (src, dst, class) in &self.b {
+      match keep_b(*class) {
+        Some(true) => edges.push((src.clone(), dst.clone())),
+        Some(false) => edges.push((dst.clone(), src.clone())),
+        None => {}
+      }
+    }
+    edges
+  }
+
+  pub(crate) fn polygons(&self, op: Operation) -> Vec<Polygon<T>> {
+    let rings = link_rings(self.edges(op))
+      .into_iter()
+      .map(|ring| self.simplify(ring))
+      .filter(|ring| ring.len() >= 3);
+    let mut shells = Vec::new();
+    let mut holes = Vec::new();
+    for ring in rings {
+      let poly = Polygon::new_unchecked(ring);
+      match poly.orientation() {
+        Orientation::CounterClockWise => shells.push(poly),
+        Orientation::ClockWise => holes.push(poly),
+        Orientation::CoLinear => {}
+      }
+    }
+    // Find the parents of all holes before modifying the shells. Locating
+    // points requires polygons without holes.
+    let parents: Vec<Option<usize>> = holes
+      .iter()
+      .map(|hole| {
+        let inside = |shell: &Polygon<T>| {
+          hole
+            .iter()
+            .any(|pt| shell.locate(pt) == PointLocation::Inside)
+        };
+        let not_outside = |shell: &Polygon<T>| {
+          hole
+            .iter()
+            .all(|pt| shell.locate(pt) != PointLocation::Outside)
+        };
+        shells
+          .iter()
+          .position(inside)
+          .or_else(|| shells.iter().position(not_outside))
+      })
+      .collect();
+    for (hole, parent) in holes.into_iter().zip(parents) {
+      if let Some(parent) = parent {
+        shells[parent].push_ring_unchecked(hole.points);
+      }
+    }
+    shells
+  }
+
+  // Remove vertices that were introduced by splitting an edge and that no
+  // longer mark a corner.
+  fn simplify(&self, mut ring: Vec<Point<T>>) -> Vec<Point<T>> {
+    let mut i = 0;
+    while ring.len() >= 3 && i < ring.len() {
+      let n = ring.len();
+      let prev = &ring[(i + n - 1) % n];
+      let next = &ring[(i + 1) % n];
+      if !self.originals.contains(&ring[i]) && Point::orient(prev, &ring[i], next).is_colinear() {
+        ring.remove(i);
+        i = i.saturating_sub(1);
+      } else {
+        i += 1;
+      }
+    }
+    ring
+  }
+}
+
+// Split the edges of both rings at every point where they touch.
+fn split_rings<T>(a: &[Point<T>], b: &[Point<T>]) -> (Vec<Point<T>>, Vec<Point<T>>)
+where
+  T: PolygonScalar,
+{
+  let mut splits_a: Vec<Vec<Point<T>>> = vec![Vec::new(); a.len()];
+  let mut splits_b: Vec<Vec<Point<T>>> = vec![Vec::new(); b.len()];
+  for i in 0..a.len() {
+    let a1 = &a[i];
+    let a2 = &a[(i + 1) % a.len()];
+    let edge_a = LineSegmentView::new(EndPoint::Inclusive(a1), EndPoint::Inclusive(a2));
+    for j in 0..b.len() {
+      let b1 = &b[j];
+      let b2 = &b[(j + 1) % b.len()];
+      let edge_b = LineSegmentView::new(EndPoint::Inclusive(b1), EndPoint::Inclusive(b2));
+      match edge_a.intersect(edge_b) {
+        None => {}
+        Some(ILineSegment::Crossing) => {
+          // Prefer existing vertices over computed crossing points.
+          let pt = if edge_a.contains(b1) {
+            Some(b1.clone())
+          } else if edge_a.contains(b2) {
+            Some(b2.clone())
+          } else if edge_b.contains(a1) {
+            Some(a1.clone())
+          } else if edge_b.contains(a2) {
+            Some(a2.clone())
+          } else {
+            Line::new_through(a1, a2).intersection_point(&Line::new_through(b1, b2))
+          };
+          if let Some(pt) = pt {
+            splits_a[i].push(pt.clone());
+            splits_b[j].push(pt);
+          }
+        }
+        Some(ILineSegment::Overlap(overlap)) => {
+          for pt in [overlap.min.inner(), overlap.max.inner()] {
+            splits_a[i].push((*pt).clone());
+            splits_b[j].push((*pt).clone());
+          }
+        }
+      }
+    }
+  }
+  (splice(a, splits_a), splice(b, splits_b))
+}
+
+fn splice<T>(ring: &[Point<T>], splits: Vec<Vec<Point<T>>>) -> Vec<Point<T>>
+where
+  T: PolygonScalar,
+{
+  let mut out: Vec<Point<T>> = Vec::with_capacity(ring.len());
+  for (i, mut pts) in splits.into_iter().enumerate() {
+    let src = &ring[i];
+    let dst = &ring[(i + 1) % ring.len()];
+    pts.sort_by(|p, q| src.cmp_distance_to(p, q));
+    out.push(src.clone());
+    for pt in pts {
+      if &pt != dst && out.last() != Some(&pt) {
+        out.push(pt);
+      }
+    }
+  }
+  out.dedup();
+  while out.len() > 1 && out.first() == out.last() {
+    out.pop();
+  }
+  out
+}
+
+// Classify each edge of 'ring' relative to the polygon bounded by 'other'.
+// 'ring' and 'other' must have been split such that edges only meet at
+// vertices.
+fn classify<T>(
+  ring: &[Point<T>],
+  other: &[Point<T>],
+  other_poly: &Polygon<T>,
+) -> Vec<(Point<T>, Point<T>, EdgeClass)>
+where
+  T: PolygonScalar,
+{
+  let n = other.len();
+  let other_edges: BTreeSet<(&Point<T>, &Point<T>)> =
+    (0..n).map(|i| (&other[i], &other[(i + 1) % n])).collect();
+  let other_index: BTreeMap<&Point<T>, usize> =
+    other.iter().enumerate().map(|(i, pt)| (pt, i)).collect();
+  let locate = |pt: &Point<T>| match other_poly.locate(pt) {
+    PointLocation::Inside => Some(EdgeClass::Inside),
+    PointLocation::Outside => Some(EdgeClass::Outside),
+    PointLocation::OnBoundary => None,
+  };
+  // Both endpoints are on the boundary of the other polygon. Check if the
+  // edge leaves 'src' into the interior.
+  let wedge = |src: &Point<T>, dst: &Point<T>| {
+    let i = match other_index.get(src) {
+      Some(&i) => i,
+      None => return EdgeClass::Outside,
+    };
+    let prev = &other[(i + n - 1) % n];
+    let next = &other[(i + 1) % n];
+    let left_of_prev = Point::orient(prev, src, dst).is_ccw();
+    let left_of_next = Point::orient(src, next, dst).is_ccw();
+    let inside = if Point::orient(prev, src, next).is_cw() {
+      left_of_prev || left_of_next
+    } else {
+      left_of_prev && left_of_next
+    };
+    if inside {
+      EdgeClass::Inside
+    } else {
+      EdgeClass::Outside
+    }
+  };
+  (0..ring.len())
+    .map(|i| {
+      let src = &ring[i];
+      let dst = &ring[(i + 1) % ring.len()];
+      let class = if other_edges.contains(&(src, dst)) {
+        EdgeClass::Shared
+      } else if other_edges.contains(&(dst, src)) {
+        EdgeClass::SharedOpposite
+      } else {
+        locate(src)
+          .or_else(|| locate(dst))
+          .unwrap_or_else(|| wedge(src, dst))
+      };
+      (src.clone(), dst.clone(), class)
+    })
+    .collect()
+}
+
+// Link directed edges into closed rings. When several edges leave the same
+// vertex, take the left-most turn such that rings touching at a single vertex
+// are kept apart.
+fn link_rings<T>(edges: Vec<(Point<T>, Point<T>)>) -> Vec<Vec<Point<T>>>
+where
+  T: PolygonScalar,
+{
+  let mut outgoing: BTreeMap<&Point<T>, Vec<usize>> = BTreeMap::new();
+  for (i, (src, _dst)) in edges.iter().enumerate() {
+    outgoing.entry(src).or_default().push(i);
+  }
+  let mut used = vec![false; edges.len()];
+  let mut rings = Vec::new();
+  for start in 0..edges.len() {
+    if used[start] {
+      continue;
+    }
+    let mut ring = vec![edges[start].0.clone()];
+    let mut current = start;
+    let closed = loop {
+      used[current] = true;
+      let (src, dst) = &edges[current];
+      if dst == &ring[0] {
+        break true;
+      }
+      let back = src - dst;
+      let candidates = outgoing.get(dst).into_iter().flatten().copied();
+      let next = candidates.filter(|&e| !used[e]).max_by(|&e1, &e2| {
+        Orientation::ccw_cmp_around_with(&back, dst, &edges[e1].1, &edges[e2].1)
+      });
+      match next {
+        Some(next) => {
+          ring.push(dst.clone());
+          current = next;
+        }
+        None => break false,
+      }
+    };
+    if closed {
+      rings.extend(split_pinches(ring));
+    }
+  }
+  rings
+}
+
+// Split a closed walk into loops that don't visit any vertex twice.
+fn split_pinches<T>(walk: Vec<Point<T>>) -> Vec<Vec<Point<T>>>
+where
+  T: PolygonScalar,
+{
+  let mut loops = Vec::new();
+  let mut stack: Vec<Point<T>> = Vec::with_capacity(walk.len());
+  let mut seen: BTreeMap<Point<T>, usize> = BTreeMap::new();
+  for pt in walk {
+    if let Some(&idx) = seen.get(&pt) {
+      let tail = stack.split_off(idx);
+      for vertex in &tail[1..] {
+        seen.remove(vertex);
+      }
+      loops.push(tail);
+    }
+    seen.insert(pt.clone(), stack.len());
+    stack.push(pt);
+  }
+  loops.push(stack);
+  loops
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::testing::square;
+  use num::{BigInt, BigRational};
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  fn big(poly: &Polygon<i8>) -> Polygon<BigRational> {
+    poly
+      .clone()
+      .map(|v| BigRational::from_integer(BigInt::from(v)))
+  }
+
+  // Sum of the signed area of every ring. Holes have negative area.
+  fn total_area(polys: &[Polygon<BigRational>]) -> BigRational {
+    let mut area = BigRational::from_integer(BigInt::from(0));
+    for poly in polys {
+      for ring in &poly.rings {
+        let n = ring.len();
+        for i in 0..n {
+          let p = poly.point(ring[i]);
+          let q = poly.point(ring[(i + 1) % n]);
+          area += p.array[0].clone() * q.array[1].clone() - q.array[0].clone() * p.array[1].clone();
+        }
+      }
+    }
+    area / BigRational::from_integer(BigInt::from(2))
+  }
+
+  #[test]
+  fn union_l_shape() {
+    let a = square(0, 0, 4);
+    let b = square(0, 3, 2);
+    let union = union(&a, &b);
+    assert_eq!(union.len(), 1);
+    assert_eq!(union[0].signed_area_2x::<i64>(), 36);
+    assert_eq!(union[0].validate().err(), None);
+  }
+
+  #[test]
+  fn union_overlapping() {
+    let a = square(0, 0, 2);
+    let b = square(1, 1, 2);
+    let union = union(&a, &b);
+    assert_eq!(union.len(), 1);
+    assert_eq!(union[0].signed_area_2x::<i64>(), 14);
+    assert_eq!(union[0].boundary_slice().len(), 8);
+    assert_eq!(union[0].validate().err(), None);
+  }
+
+  #[test]
+  fn union_disjoint() {
+    let a = square(0, 0, 2);
+    let b = square(5, 5, 2);
+    let union = union(&a, &b);
+    assert_eq!(union.len(), 2);
+    assert!(union[0].equals(&a));
+    assert!(union[1].equals(&b));
+  }
+
+  #[test]
+  fn union_nested() {
+    let a = square(0, 0, 10);
+    let b = square(2, 2, 3);
+    let union = union(&a, &b);
+    assert_eq!(union.len(), 1);
+    assert!(union[0].equals(&a));
+    let union = super::union(&b, &a);
+    assert_eq!(union.len(), 1);
+    assert!(union[0].equals(&a));
+  }
+
+  #[test]
+  fn union_identical() {
+    let a = square(0, 0, 2);
+    let union = union(&a, &a);
+    assert_eq!(union.len(), 1);
+    assert!(union[0].equals(&a));
+  }
+
+  #[test]
+  fn union_touching_corner() {
+    let a = square(0, 0, 2);
+    let b = square(2, 2, 2);
+    let union = union(&a, &b);
+    assert_eq!(union.len(), 2);
+  }
+
+  #[test]
+  fn union_with_hole() {
+    // Two U shapes that enclose a square hole.
+    let a = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([3, 0]),
+      Point::new([3, 1]),
+      Point::new([1, 1]),
+      Point::new([1, 2]),
+      Point::new([3, 2]),
+      Point::new([3, 3]),
+      Point::new([0, 3]),
+    ])
+    .unwrap();
+    let b = Polygon::new(vec![
+      Point::new([2, 0]),
+      Point::new([4, 0]),
+      Point::new([4, 3]),
+      Point::new([2, 3]),
+      Point::new([2, 2]),
+      Point::new([3, 2]),
+      Point::new([3, 1]),
+      Point::new([2, 1]),
+    ])
+    .unwrap();
+    let union = union(&a, &b);
+    assert_eq!(union.len(), 1);
+    assert_eq!(union[0].rings.len(), 2);
+    assert_eq!(union[0].signed_area_2x::<i64>(), 24);
+  }
+
+  #[test]
+  fn difference_concentric() {
+    let a = square(0, 0, 6);
+    let b = square(2, 2, 2);
+    let difference = difference(&a, &b);
+    assert_eq!(difference.len(), 1);
+    let poly = &difference[0];
+    assert_eq!(poly.rings.len(), 2);
+    assert!(poly.equals(&a));
+    let hole: Vec<Point<i64>> = poly.rings[1].iter().map(|&pid| *poly.point(pid)).collect();
+    let hole = Polygon::new_unchecked(hole);
+    assert_eq!(hole.orientation(), Orientation::ClockWise);
+    assert_eq!(hole.signed_area_2x::<i64>(), -8);
+  }
+
+  #[test]
+  fn difference_disjoint() {
+    let a = square(0, 0, 2);
+    let b = square(5, 5, 2);
+    let difference = difference(&a, &b);
+    assert_eq!(difference.len(), 1);
+    assert!(difference[0].equals(&a));
+  }
+
+  #[test]
+  fn difference_covered() {
+    let a = square(2, 2, 2);
+    let b = square(0, 0, 6);
+    assert!(difference(&a, &b).is_empty());
+    assert!(difference(&a, &a).is_empty());
+  }
+
+  #[test]
+  fn difference_split() {
+    // A vertical bar cuts the square in two.
+    let a = square(0, 0, 6);
+    let b = Polygon::new(vec![
+      Point::new([2, -1]),
+      Point::new([4, -1]),
+      Point::new([4, 7]),
+      Point::new([2, 7]),
+    ])
+    .unwrap();
+    let difference = difference(&a, &b);
+    assert_eq!(difference.len(), 2);
+    for poly in &difference {
+      assert_eq!(poly.signed_area_2x::<i64>(), 24);
+    }
+  }
+
+  #[proptest]
+  fn union_area_prop(#[any((3..12, ()))] a: Polygon<i8>, #[any((3..12, ()))] b: Polygon<i8>) {
+    let a = big(&a);
+    let b = big(&b);
+    let area_a = a.signed_area::<BigRational>();
+    let area_b = b.signed_area::<BigRational>();
+    let polys = union(&a, &b);
+    let area = total_area(&polys);
+    prop_assert!(area >= area_a.clone().max(area_b.clone()));
+    prop_assert!(area <= area_a + area_b);
+    // Holes may touch the outer boundary so only the outer boundary is
+    // required to be simple.
+    for poly in &polys {
+      prop_assert_eq!(poly.validate_weakly().err(), None);
+    }
+  }
+
+  // area(a - b) = area(a ∪ b) - area(b)
+  #[proptest]
+  fn difference_area_prop(#[any((3..12, ()))] a: Polygon<i8>, #[any((3..12, ()))] b: Polygon<i8>) {
+    let a = big(&a);
+    let b = big(&b);
+    let area_b = b.signed_area::<BigRational>();
+    let polys = difference(&a, &b);
+    prop_assert_eq!(total_area(&polys), total_area(&union(&a, &b)) - area_b);
+    for poly in &polys {
+      prop_assert_eq!(poly.validate_weakly().err(), None);
+    }
+  }
+}
@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+
+use num::BigRational;
+
+use crate::data::{Point, Polygon};
+use crate::{Error, Orientation, PolygonScalar, ToRational};
+
+/// Concave hull (alpha shape) of a set of points.
+///
+/// The points are triangulated with a Delaunay triangulation and every
+/// triangle whose circumradius squared exceeds `alpha_squared` is dropped.
+/// The outline of the remaining triangles is returned, starting at its
+/// lexicographically smallest vertex. Large values of
+/// `alpha_squared` keep every triangle and give the convex hull, including
+/// any input points that lie on its edges.
+///
+/// Circumradii are compared exactly.
+///
+/// # Errors
+///
+/// Returns [`Error::InsufficientVertices`] if fewer than three distinct
+/// points are given, if they are all colinear or if no triangle survives.
+/// Returns [`Error::SelfIntersections`] if the surviving triangles don't form
+/// a single region without holes, for example when they touch at a vertex.
+///
+/// # Time complexity
+///
+/// $O(n^2)$
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// # use rgeometry::algorithms::concave_hull::concave_hull;
+/// let pts = vec![
+///   Point::new([0, 0]),
+///   Point::new([4, 0]),
+///   Point::new([4, 4]),
+///   Point::new([0, 4]),
+///   Point::new([2, 3]),
+/// ];
+/// // Only the triangle above the fifth point has a circumradius squared above
+/// // five, and it is dropped.
+/// let hull = concave_hull(pts.clone(), 5).unwrap();
+/// assert_eq!(hull.signed_area::<f64>(), 14.0);
+/// // Nothing is dropped and the convex hull remains.
+/// let hull = concave_hull(pts, 100).unwrap();
+/// assert_eq!(hull.signed_area::<f64>(), 16.0);
+/// ```
+pub fn concave_hull<T: PolygonScalar + ToRational>(
+  mut points: Vec<Point<T, 2>>,
+  alpha_squared: T,
+) -> Result<Polygon<T>, Error> {
+  points.sort();
+  points.dedup();
+  let triangles = delaunay(&points)?;
+
+  let alpha_squared = alpha_squared.to_rational();
+  let kept: Vec<bool> = triangles
+    .iter()
+    .map(|&[a, b, c]| {
+      let [a, b, c] =
+        [&points[a], &points[b], &points[c]].map(|p| p.array.clone().map(|v| v.to_rational()));
+      let sub = |p: &[BigRational; 2], q: &[BigRational; 2]| [&p[0] - &q[0], &p[1] - &q[1]];
+      let (ab, bc, ca) = (sub(&b, &a), sub(&c, &b), sub(&a, &c));
+      let len = |v: &[BigRational; 2]| &v[0] * &v[0] + &v[1] * &v[1];
+      let cross = &ab[0] * &ca[1] - &ab[1] * &ca[0];
+      // R^2 = |ab|^2 |bc|^2 |ca|^2 / (4 * cross^2)
+      len(&ab) * len(&bc) * len(&ca)
+        <= BigRational::from_integer(4.into()) * &alpha_squared * &cross * &cross
+    })
+    .collect();
+  if !kept.contains(&true) {
+    return Err(Error::InsufficientVertices);
+  }
+
+  // Edges of kept triangles whose twin isn't kept, keyed by their source.
+  let owner: HashMap<(usize, usize), usize> = triangles
+    .iter()
+    .enumerate()
+    .flat_map(|(t, &[a, b, c])| [((a, b), t), ((b, c), t), ((c, a), t)])
+    .collect();
+  let mut next: HashMap<usize, usize> = HashMap::new();
+  for (t, &[a, b, c]) in triangles.iter().enumerate() {
+    if !kept[t] {
+      continue;
+    }
+    for (u, v) in [(a, b), (b, c), (c, a)] {
+      if owner.get(&(v, u)).is_some_and(|&twin| kept[twin]) {
+        continue;
+      }
+      if next.insert(u, v).is_some() {
+        return Err(Error::SelfIntersections);
+      }
+    }
+  }
+  // Points are sorted so the ring starts at its lexicographically smallest
+  // vertex.
+  let start = *next.keys().min().unwrap();
+  let mut ring = vec![start];
+  let mut cur = next[&start];
+  while cur != start {
+    ring.push(cur);
+    cur = next[&cur];
+  }
+  if ring.len() != next.len() {
+    return Err(Error::SelfIntersections);
+  }
+  Polygon::new(ring.into_iter().map(|i| points[i].clone()).collect())
+}
+
+// Delaunay triangulation of sorted, distinct points. Triangles are
+// counter-clockwise triples of indices.
+fn delaunay<T: PolygonScalar>(points: &[Point<T, 2>]) -> Result<Vec<[usize; 3]>, Error> {
+  let mut triangles = sweep_triangulation(points)?;
+  let mut owner: HashMap<(usize, usize), usize> = HashMap::new();
+  for (t, &[a, b, c]) in triangles.iter().enumerate() {
+    owner.extend([((a, b), t), ((b, c), t), ((c, a), t)]);
+  }
+  // Lawson flips: flip edges until every edge is locally Delaunay.
+  let mut stack: Vec<(usize, usize)> = owner.keys().copied().collect();
+  while let Some((a, b)) = stack.pop() {
+    let (Some(&t1), Some(&t2)) = (owner.get(&(a, b)), owner.get(&(b, a))) else {
+      continue;
+    };
+    let c = opposite(triangles[t1], a, b);
+    let d = opposite(triangles[t2], b, a);
+    let [pa, pb, pc, pd] = [a, b, c, d].map(|i| &points[i].array);
+    if T::incircle(pa, pb, pc, pd) != std::cmp::Ordering::Greater {
+      continue;
+    }
+    owner.remove(&(a, b));
+    owner.remove(&(b, a));
+    triangles[t1] = [c, a, d];
+    triangles[t2] = [d, b, c];
+    owner.extend([((c, a), t1), ((a, d), t1), ((d, c), t1)]);
+    owner.extend([((d, b), t2), ((b, c), t2), ((c, d), t2)]);
+    stack.extend([(a, d), (d, b), (b, c), (c, a)]);
+  }
+  Ok(triangles)
+}
+
+// Vertex of `triangle` that isn't on its edge from `a` to `b`.
+fn opposite(triangle: [usize; 3], a: usize, b: usize) -> usize {
+  let i = triangle.iter().position(|&v| v == a).unwrap();
+  debug_assert_eq!(triangle[(i + 1) % 3], b);
+  triangle[(i + 2) % 3]
+}
+
+// Triangulate sorted, distinct points by adding them from left to right and
+// connecting each to the edges of the hull it can see.
+fn sweep_triangulation<T: PolygonScalar>(points: &[Point<T, 2>]) -> Result<Vec<[usize; 3]>, Error> {
+  if points.len() < 3 {
+    return Err(Error::InsufficientVertices);
+  }
+  // The first points may be colinear. Fan them out from the first point off
+  // their line.
+  let apex = (2..points.len())
+    .find(|&k| !Point::orient(&points[0], &points[1], &points[k]).is_colinear())
+    .ok_or(Error::InsufficientVertices)?;
+  let ccw = Point::orient(&points[0], &points[1], &points[apex]) == Orientation::CounterClockWise;
+  let mut triangles = Vec::new();
+  for i in 0..apex - 1 {
+    triangles.push(if ccw {
+      [i, i + 1, apex]
+    } else {
+      [i + 1, i, apex]
+    });
+  }
+  let mut hull: Vec<usize> = (0..apex).collect();
+  if !ccw {
+    hull.reverse();
+  }
+  hull.push(apex);
+
+  for p in apex + 1..points.len() {
+    let n = hull.len();
+    let visible = |j: usize| {
+      Point::orient(&points[hull[j % n]], &points[hull[(j + 1) % n]], &points[p])
+        == Orientation::ClockWise
+    };
+    // Every new point is lexicographically largest so it lies outside the
+    // hull and sees at least one edge.
+    let mut start = (0..n).find(|&j| visible(j)).unwrap();
+    while visible(start + n - 1) {
+      start = (start + n - 1) % n;
+    }
+    let mut count = 1;
+    while visible(start + count) {
+      count += 1;
+    }
+    hull.rotate_left(start);
+    for j in 0..count {
+      triangles.push([hull[j], p, hull[j + 1]]);
+    }
+    hull.splice(1..count, [p]);
+  }
+  Ok(triangles)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::algorithms::convex_hull;
+  use crate::data::PointLocation;
+
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  // Grid points of a C opening to the right.
+  fn c_shape() -> Vec<Point<i32>> {
+    (0..=6)
+      .flat_map(|x| (0..=6).map(move |y| Point::new([x, y])))
+      .filter(|pt| !(pt.array[0] >= 2 && (2..=4).contains(&pt.array[1])))
+      .collect()
+  }
+
+  #[test]
+  fn c_shape_concave() {
+    let hull = concave_hull(c_shape(), 1).unwrap();
+    assert_eq!(hull.validate().err(), None);
+    // 16 unit squares and two corner triangles where the arms meet the spine.
+    assert_eq!(hull.signed_area::<f64>(), 17.0);
+    assert_eq!(hull.locate(&Point::new([4, 3])), PointLocation::Outside);
+    assert_eq!(hull.locate(&Point::new([1, 3])), PointLocation::OnBoundary);
+  }
+
+  #[test]
+  fn c_shape_convex() {
+    let hull = concave_hull(c_shape(), i32::MAX).unwrap();
+    assert_eq!(hull.iter().next(), Some(&Point::new([0, 0])));
+    assert_eq!(hull.signed_area::<f64>(), 36.0);
+    assert_eq!(hull.locate(&Point::new([4, 3])), PointLocation::Inside);
+  }
+
+  #[test]
+  fn degenerate() {
+    let colinear = vec![Point::new([0, 0]), Point::new([1, 1]), Point::new([2, 2])];
+    assert_eq!(
+      concave_hull(colinear, 100).err(),
+      Some(Error::InsufficientVertices)
+    );
+    let triangle = vec![Point::new([0, 0]), Point::new([4, 0]), Point::new([0, 4])];
+    assert_eq!(
+      concave_hull(triangle, 1).err(),
+      Some(Error::InsufficientVertices)
+    );
+  }
+
+  #[proptest]
+  fn delaunay_prop(#[strategy(vec(any::<[u8; 2]>(), 3..25))] pts: Vec<[u8; 2]>) {
+    // Small coordinates give plenty of colinear and cocircular points.
+    let mut pts: Vec<Point<i32>> = pts
+      .into_iter()
+      .map(|[x, y]| Point::new([i32::from(x % 8), i32::from(y % 8)]))
+      .collect();
+    pts.sort();
+    pts.dedup();
+    if let Ok(triangles) = delaunay(&pts) {
+      for [a, b, c] in triangles {
+        let (a, b, c) = (&pts[a], &pts[b], &pts[c]);
+        prop_assert_eq!(Point::orient(a, b, c), Orientation::CounterClockWise);
+        for d in &pts {
+          prop_assert_ne!(
+            i32::incircle(&a.array, &b.array, &c.array, &d.array),
+            std::cmp::Ordering::Greater
+          );
+        }
+      }
+    }
+  }
+
+  #[proptest]
+  fn large_alpha_prop(#[strategy(vec(any::<Point<i8>>(), 3..25))] pts: Vec<Point<i8>>) {
+    let pts: Vec<Point<i64>> = pts.iter().map(|pt| pt.cast()).collect();
+    if let Ok(convex) = convex_hull(pts.clone()) {
+      let hull = concave_hull(pts.clone(), i64::MAX).unwrap();
+      prop_assert_eq!(hull.validate().err(), None);
+      prop_assert_eq!(hull.signed_area_2x::<i64>(), convex.signed_area_2x::<i64>());
+      for pt in hull.iter() {
+        prop_assert!(pts.contains(pt));
+      }
+    }
+  }
+
+  #[proptest]
+  fn alpha_prop(
+    #[strategy(vec(any::<Point<i8>>(), 3..25))] pts: Vec<Point<i8>>,
+    #[strategy(0..40_000i64)] alpha_squared: i64,
+  ) {
+    let pts: Vec<Point<i64>> = pts.iter().map(|pt| pt.cast()).collect();
+    if let Ok(hull) = concave_hull(pts.clone(), alpha_squared) {
+      prop_assert_eq!(hull.validate().err(), None);
+      for pt in hull.iter() {
+        prop_assert!(pts.contains(pt));
+      }
+    }
+  }
+}
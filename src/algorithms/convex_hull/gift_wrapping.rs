@@ -22,7 +22,7 @@ use crate::{Error, Orientation, PolygonScalar, TotalOrd};
 /// * All vertices in the convex polygon are from the input set.
 ///
 /// # Time complexity
-/// $O(n \log h)$ as h is the size of the points on convex hull
+/// $O(nh)$ where h is the number of vertices on the convex hull
 ///
 /// # Examples
 ///
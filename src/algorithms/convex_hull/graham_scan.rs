@@ -1,4 +1,4 @@
-use crate::data::{Point, Polygon, PolygonConvex};
+use crate::data::{Point, PointId, Polygon, PolygonConvex};
 use crate::{Error, Orientation, PolygonScalar, TotalOrd};
 
 // https://en.wikipedia.org/wiki/Graham_scan
@@ -129,6 +129,81 @@ where
   Ok(PolygonConvex::new_unchecked(Polygon::new_unchecked(pts)))
 }
 
+/// Upper chain of the convex hull of points that are sorted
+/// lexicographically, from the leftmost to the rightmost point. Colinear
+/// points on the chain are left out. The ids index into `pts`.
+///
+/// # Panics
+///
+/// May panic in debug builds if `pts` is not sorted.
+///
+/// # Time complexity
+/// $O(n)$
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::algorithms::convex_hull::graham_scan::{lower_hull, upper_hull};
+/// # use rgeometry::data::Point;
+/// let pts = vec![
+///   Point::new([0, 0]),
+///   Point::new([1, -1]),
+///   Point::new([1, 2]),
+///   Point::new([2, 2]),
+///   Point::new([3, 0]),
+/// ];
+/// let upper: Vec<usize> = upper_hull(&pts).into_iter().map(|id| id.usize()).collect();
+/// let lower: Vec<usize> = lower_hull(&pts).into_iter().map(|id| id.usize()).collect();
+/// assert_eq!(upper, vec![0, 2, 3, 4]);
+/// assert_eq!(lower, vec![0, 1, 4]);
+/// ```
+pub fn upper_hull<T>(pts: &[Point<T>]) -> Vec<PointId>
+where
+  T: PolygonScalar,
+{
+  monotone_chain(pts, Orientation::ClockWise)
+}
+
+/// Lower chain of the convex hull of points that are sorted
+/// lexicographically, from the leftmost to the rightmost point. Colinear
+/// points on the chain are left out. The ids index into `pts`.
+///
+/// # Panics
+///
+/// May panic in debug builds if `pts` is not sorted.
+///
+/// # Time complexity
+/// $O(n)$
+pub fn lower_hull<T>(pts: &[Point<T>]) -> Vec<PointId>
+where
+  T: PolygonScalar,
+{
+  monotone_chain(pts, Orientation::CounterClockWise)
+}
+
+// Andrew's monotone chain. Only keeps vertices where the chain turns in
+// direction 'turn'.
+fn monotone_chain<T>(pts: &[Point<T>], turn: Orientation) -> Vec<PointId>
+where
+  T: PolygonScalar,
+{
+  debug_assert!(pts.windows(2).all(|w| w[0] <= w[1]));
+  let mut chain: Vec<PointId> = Vec::new();
+  for (idx, pt) in pts.iter().enumerate() {
+    if chain.last().is_some_and(|&last| &pts[last.usize()] == pt) {
+      continue;
+    }
+    while let [.., a, b] = chain[..] {
+      if Point::orient(&pts[a.usize()], &pts[b.usize()], pt) == turn {
+        break;
+      }
+      chain.pop();
+    }
+    chain.push(PointId(idx));
+  }
+  chain
+}
+
 // Find the smallest point and remove it from the vector
 // O(n)
 fn smallest_point<T>(pts: &[Point<T>]) -> Result<Point<T>, Error>
@@ -252,6 +327,44 @@ mod tests {
     assert_ok!(poly.validate());
   }
 
+  #[test]
+  fn chains_of_square() {
+    let pts: Vec<Point<i32>> = vec![
+      Point::new([0, 0]),
+      Point::new([0, 2]),
+      Point::new([1, 0]),
+      Point::new([1, 1]),
+      Point::new([1, 1]),
+      Point::new([2, 0]),
+      Point::new([2, 2]),
+    ];
+    let ids =
+      |chain: Vec<PointId>| -> Vec<usize> { chain.into_iter().map(|id| id.usize()).collect() };
+    assert_eq!(ids(upper_hull(&pts)), vec![0, 1, 6]);
+    assert_eq!(ids(lower_hull(&pts)), vec![0, 5, 6]);
+    assert_eq!(ids(upper_hull(&pts[..1])), vec![0]);
+    assert!(upper_hull::<i32>(&[]).is_empty());
+  }
+
+  #[proptest]
+  fn chains_make_hull(#[strategy(vec(any::<Point<i8>>(), 0..100))] mut pts: Vec<Point<i8>>) {
+    pts.sort();
+    if let Ok(poly) = convex_hull(pts.clone()) {
+      let mut upper = upper_hull(&pts);
+      upper.reverse();
+      let lower = lower_hull(&pts);
+      let mut chained: Vec<&Point<i8>> = lower
+        .iter()
+        .chain(&upper[1..upper.len() - 1])
+        .map(|id| &pts[id.usize()])
+        .collect();
+      let mut hull: Vec<&Point<i8>> = poly.iter().collect();
+      chained.sort();
+      hull.sort();
+      prop_assert_eq!(chained, hull);
+    }
+  }
+
   #[proptest]
   fn convex_hull_prop(#[strategy(vec(any_r(), 0..100))] pts: Vec<Point<BigInt>>) {
     if let Ok(poly) = convex_hull(pts.clone()) {
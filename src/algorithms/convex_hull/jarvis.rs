@@ -0,0 +1,53 @@
+//! Jarvis march, better known as [gift wrapping](super::gift_wrapping).
+//!
+//! Output-sensitive: runs in $O(nh)$ time where $h$ is the number of hull
+//! vertices, which beats $O(n \log n)$ algorithms when the hull is small.
+pub use super::gift_wrapping::convex_hull;
+
+#[cfg(test)]
+#[cfg(not(tarpaulin_include))]
+mod tests {
+  use super::*;
+  use crate::algorithms::convex_hull::graham_scan;
+  use crate::data::Point;
+
+  use proptest::collection::*;
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  #[test]
+  fn square_with_interior_points() {
+    let mut pts: Vec<Point<i32>> = (1..10)
+      .flat_map(|x| (1..10).map(move |y| Point::new([x, y])))
+      .collect();
+    pts.extend([
+      Point::new([0, 10]),
+      Point::new([10, 10]),
+      Point::new([0, 0]),
+      Point::new([10, 0]),
+    ]);
+    let hull = convex_hull(pts).unwrap();
+    let vertices: Vec<&Point<i32>> = hull.iter().collect();
+    assert_eq!(
+      vertices,
+      vec![
+        &Point::new([0, 0]),
+        &Point::new([10, 0]),
+        &Point::new([10, 10]),
+        &Point::new([0, 10]),
+      ]
+    );
+  }
+
+  #[proptest]
+  fn matches_graham_scan_prop(#[strategy(vec(any::<Point<i8>>(), 0..100))] pts: Vec<Point<i8>>) {
+    match (convex_hull(pts.clone()), graham_scan::convex_hull(pts)) {
+      (Ok(jarvis), Ok(graham)) => {
+        let jarvis: Vec<&Point<i8>> = jarvis.iter().collect();
+        let graham: Vec<&Point<i8>> = graham.iter().collect();
+        prop_assert_eq!(jarvis, graham);
+      }
+      (jarvis, graham) => prop_assert_eq!(jarvis.err(), graham.err()),
+    }
+  }
+}
@@ -9,22 +9,44 @@ use std::collections::VecDeque;
 // In the right region we pop from the left side, and vice versa.
 // In the composition region, which is the region shared by right and left, we pop the deque from both sides.
 
+/// Convex hull of a simple polygon using Melkman's algorithm.
+///
+/// Repeated vertices are collapsed: consecutive duplicates are skipped and
+/// the colinear vertices at the start of the boundary only contribute their
+/// two extreme points. If every vertex is colinear, the result is degenerate
+/// (one or two vertices) and fails [`validate`](Polygon::validate).
+///
+/// # Time complexity
+/// $O(n)$
 pub fn convex_hull<T>(polygon: &Polygon<T>) -> PolygonConvex<T>
 where
   T: PolygonScalar,
 {
   let mut convex_hull: VecDeque<&Point<T, 2>> = VecDeque::new();
   let mut last_idx = 0;
+  let mut prev: Option<&Point<T, 2>> = None;
   for p in polygon.iter() {
+    // Skip consecutive duplicates.
+    if prev == Some(p) {
+      continue;
+    }
+    prev = Some(p);
     // Creat a deque with the first 3 points
     if convex_hull.len() < 2 {
       convex_hull.push_back(p);
+      if convex_hull.len() == 2 && convex_hull[1] < convex_hull[0] {
+        convex_hull.swap(0, 1);
+      }
 
-    // Check for colinear of the first 3 points and remove last verdix if so
+    // While the points are colinear, only keep the two extremes. The deque is
+    // kept sorted so the extremes are at the front and back.
     } else if convex_hull.len() == 2 {
       if Point::orient(convex_hull[0], convex_hull[1], p).is_colinear() {
-        convex_hull.pop_back();
-        convex_hull.push_back(p);
+        if p < convex_hull[0] {
+          convex_hull[0] = p;
+        } else if p > convex_hull[1] {
+          convex_hull[1] = p;
+        }
         continue;
       }
       convex_hull.push_front(p);
@@ -76,8 +98,10 @@ where
       }
     }
   }
-  // Pop last duplicated verdix
-  convex_hull.pop_back();
+  // Pop last duplicated verdix. Colinear input never gets duplicated.
+  if convex_hull.len() > 2 {
+    convex_hull.pop_back();
+  }
 
   let polygon = Polygon::new_unchecked(convert_deque_to_vec(convex_hull));
   PolygonConvex::new_unchecked(polygon)
@@ -97,6 +121,7 @@ mod tests {
 
   use claims::assert_ok;
 
+  use proptest::prelude::*;
   use test_strategy::proptest;
 
   #[test]
@@ -172,6 +197,68 @@ mod tests {
     assert!(Polygon::equals(&convex_hull(&input), &output));
   }
 
+  #[test]
+  fn duplicate_points() {
+    let hull = convex_hull(&Polygon::new_unchecked(vec![
+      Point::new([0, 0]),
+      Point::new([1, 0]),
+      Point::new([0, 0]),
+      Point::new([0, 1]),
+    ]));
+    let output = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([1, 0]),
+      Point::new([0, 1]),
+    ])
+    .unwrap();
+    assert!(Polygon::equals(&hull, &output));
+
+    let hull = convex_hull(&Polygon::new_unchecked(vec![
+      Point::new([0, 0]),
+      Point::new([0, 0]),
+      Point::new([1, 0]),
+      Point::new([2, 0]),
+      Point::new([1, 0]),
+      Point::new([2, 2]),
+      Point::new([2, 2]),
+      Point::new([0, 2]),
+      Point::new([0, 0]),
+    ]));
+    let output = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([2, 0]),
+      Point::new([2, 2]),
+      Point::new([0, 2]),
+    ])
+    .unwrap();
+    assert!(Polygon::equals(&hull, &output));
+  }
+
+  #[test]
+  fn colinear_input() {
+    let hull = convex_hull(&Polygon::new_unchecked(vec![
+      Point::new([1, 0]),
+      Point::new([0, 0]),
+      Point::new([2, 0]),
+      Point::new([1, 0]),
+    ]));
+    let pts: Vec<Point<i32>> = hull.iter().cloned().collect();
+    assert_eq!(pts, vec![Point::new([0, 0]), Point::new([2, 0])]);
+  }
+
+  // Duplicating vertices of a simple polygon doesn't change its hull.
+  #[proptest]
+  fn duplicates_prop(poly: Polygon<i8>, #[strategy(0..100_usize)] k: usize) {
+    let mut pts: Vec<Point<i8>> = poly.iter_boundary().map(|c| *c.point()).collect();
+    for j in 0..k % 5 + 1 {
+      let i = (k * 7 + j * 3) % pts.len();
+      pts.insert(i, pts[i]);
+    }
+    pts.push(pts[0]);
+    let hull = convex_hull(&Polygon::new_unchecked(pts));
+    prop_assert!(Polygon::equals(&hull, &convex_hull(&poly)));
+  }
+
   #[proptest]
   fn does_not_panic(poly: Polygon<i8>) {
     convex_hull(&poly);
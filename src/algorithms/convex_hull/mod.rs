@@ -1,3 +1,83 @@
+use crate::data::{Polygon, PolygonConvex};
+use crate::{Error, PolygonScalar};
+
 pub mod gift_wrapping;
 pub mod graham_scan;
+pub mod jarvis;
 pub mod melkman;
+pub mod quickhull;
+
+/// Convex hull of the vertices of several polygons.
+///
+/// Equivalent to running [`graham_scan::convex_hull`] on the concatenated
+/// vertices.
+///
+/// # Errors
+/// Will return an error iff the polygons have less than three distinct,
+/// non-colinear vertices in total.
+///
+/// # Time complexity
+/// $O(n \log n)$
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// # use rgeometry::algorithms::convex_hull::hull_of_polygons;
+/// # fn main() -> Result<(), rgeometry::Error> {
+/// let a = Polygon::new(vec![Point::new([0, 0]), Point::new([2, 0]), Point::new([0, 2])])?;
+/// let b = Polygon::new(vec![Point::new([4, 0]), Point::new([6, 0]), Point::new([6, 2])])?;
+/// assert_eq!(hull_of_polygons(&[a, b])?.iter().count(), 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn hull_of_polygons<T>(polys: &[Polygon<T>]) -> Result<PolygonConvex<T>, Error>
+where
+  T: PolygonScalar,
+{
+  let pts = polys.iter().flat_map(|poly| poly.iter().cloned()).collect();
+  graham_scan::convex_hull(pts)
+}
+
+#[cfg(test)]
+#[cfg(not(tarpaulin_include))]
+mod tests {
+  use super::*;
+  use crate::data::Point;
+  use crate::testing::square;
+
+  #[test]
+  fn two_squares() {
+    let hull = hull_of_polygons(&[square(0, 0, 2), square(5, 1, 2)]).unwrap();
+    let vertices: Vec<&Point<i32>> = hull.iter().collect();
+    assert_eq!(
+      vertices,
+      vec![
+        &Point::new([0, 0]),
+        &Point::new([2, 0]),
+        &Point::new([7, 1]),
+        &Point::new([7, 3]),
+        &Point::new([5, 3]),
+        &Point::new([0, 2]),
+      ]
+    );
+  }
+
+  #[test]
+  fn insufficient() {
+    let empty: [Polygon<i32>; 0] = [];
+    assert_eq!(
+      hull_of_polygons(&empty).err(),
+      Some(Error::InsufficientVertices)
+    );
+    let line = Polygon::new_unchecked(vec![
+      Point::new([0, 0]),
+      Point::new([1, 1]),
+      Point::new([2, 2]),
+    ]);
+    assert_eq!(
+      hull_of_polygons(&[line]).err(),
+      Some(Error::InsufficientVertices)
+    );
+  }
+}
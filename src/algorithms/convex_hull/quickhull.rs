@@ -0,0 +1,224 @@
+use crate::data::{Point, Polygon, PolygonConvex, Vector};
+use crate::{Error, Orientation, PolygonScalar, TotalOrd};
+
+// https://en.wikipedia.org/wiki/Quickhull
+
+// Properties:
+//    All Ok results are valid convex polygons.
+//    No points are outside the resulting convex polygon.
+/// Convex hull of a set of points.
+///
+/// [Quickhull][wiki] algorithm for finding the smallest convex polygon which
+/// contains all the given points. The points are split by the line through the
+/// leftmost and rightmost points and each half is recursively split by the
+/// point farthest from the dividing line.
+///
+/// The output is identical to [`graham_scan::convex_hull`](super::graham_scan::convex_hull):
+/// colinear points are dropped and the first vertex is the lowest point.
+///
+/// # Errors
+/// Will return an error iff the input set contains less than three distinct points.
+///
+/// # Panics
+/// May panic for bounded types (i8, isize, etc) if the difference between two
+/// coordinates overflows.
+///
+/// # Properties
+/// * No points from the input set will be outside the returned convex polygon.
+/// * All vertices in the convex polygon are from the input set.
+///
+/// # Time complexity
+/// $O(n \log n)$ expected, $O(n^2)$ worst case.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// # use rgeometry::algorithms::convex_hull::quickhull::convex_hull;
+/// # use rgeometry::data::Point;
+/// let pts = vec![
+///   Point::new([0, 0]),
+///   Point::new([2, 0]),
+///   Point::new([1, 1]),
+///   Point::new([2, 2]),
+///   Point::new([0, 2]),
+/// ];
+/// let hull = convex_hull(pts).unwrap();
+/// assert_eq!(hull.iter().count(), 4);
+/// # }
+/// ```
+///
+/// [wiki]: https://en.wikipedia.org/wiki/Quickhull
+pub fn convex_hull<T>(pts: Vec<Point<T>>) -> Result<PolygonConvex<T>, Error>
+where
+  T: PolygonScalar,
+{
+  let leftmost = pts.iter().min().ok_or(Error::InsufficientVertices)?.clone();
+  let rightmost = pts.iter().max().ok_or(Error::InsufficientVertices)?.clone();
+  if leftmost == rightmost {
+    return Err(Error::InsufficientVertices);
+  }
+  let (below, above): (Vec<Point<T>>, Vec<Point<T>>) = pts
+    .into_iter()
+    .filter(|pt| !Point::orient(&leftmost, &rightmost, pt).is_colinear())
+    .partition(|pt| Point::orient(&leftmost, &rightmost, pt).is_cw());
+
+  let mut hull = Vec::new();
+  hull.push(leftmost.clone());
+  find_hull(&leftmost, &rightmost, below, &mut hull);
+  hull.push(rightmost.clone());
+  find_hull(&rightmost, &leftmost, above, &mut hull);
+  if hull.len() < 3 {
+    return Err(Error::InsufficientVertices);
+  }
+  // Start from the lowest point.
+  let lowest = (0..hull.len())
+    .min_by(|&a, &b| {
+      TotalOrd::total_cmp(
+        &(hull[a].y_coord(), hull[a].x_coord()),
+        &(hull[b].y_coord(), hull[b].x_coord()),
+      )
+    })
+    .unwrap_or(0);
+  hull.rotate_left(lowest);
+  Ok(PolygonConvex::new_unchecked(Polygon::new_unchecked(hull)))
+}
+
+// Push the hull vertices strictly between 'a' and 'b'. All points in 'pts'
+// are strictly to the right of the line from 'a' to 'b'.
+fn find_hull<T>(a: &Point<T>, b: &Point<T>, pts: Vec<Point<T>>, hull: &mut Vec<Point<T>>)
+where
+  T: PolygonScalar,
+{
+  let direction: Vector<T, 2> = b - a;
+  let farthest = match pts.iter().reduce(|best, pt| {
+    // 'pt' is farther away if it lies to the right of the line through
+    // 'best' parallel to 'a -> b'. Ties are broken by picking the smallest
+    // point so the farthest point is always a hull vertex.
+    match Point::orient_along_vector(best, &direction, pt) {
+      Orientation::ClockWise => pt,
+      Orientation::CoLinear if pt < best => pt,
+      _ => best,
+    }
+  }) {
+    Some(farthest) => farthest.clone(),
+    None => return,
+  };
+  let mut right_of_ac = Vec::new();
+  let mut right_of_cb = Vec::new();
+  for pt in pts {
+    if Point::orient(a, &farthest, &pt).is_cw() {
+      right_of_ac.push(pt);
+    } else if Point::orient(&farthest, b, &pt).is_cw() {
+      right_of_cb.push(pt);
+    }
+  }
+  find_hull(a, &farthest, right_of_ac, hull);
+  hull.push(farthest.clone());
+  find_hull(&farthest, b, right_of_cb, hull);
+}
+
+#[cfg(test)]
+#[cfg(not(tarpaulin_include))]
+mod tests {
+  use super::*;
+  use crate::algorithms::convex_hull::graham_scan;
+  use crate::data::PointLocation;
+
+  use claims::assert_ok;
+
+  use proptest::collection::*;
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  #[test]
+  fn convex_hull_colinear() {
+    let points = vec![
+      Point::new([0, 0]),
+      Point::new([1, 0]),
+      Point::new([2, 0]),
+      Point::new([3, 0]),
+      Point::new([4, 0]),
+      Point::new([1, 1]),
+    ];
+    let poly = convex_hull(points).unwrap();
+    assert_ok!(poly.validate());
+    assert_eq!(poly.iter().count(), 3);
+  }
+
+  #[test]
+  fn convex_hull_dups() {
+    let points = vec![
+      Point::new([0, 0]),
+      Point::new([1, 0]),
+      Point::new([0, 0]),
+      Point::new([1, 0]),
+      Point::new([2, 2]),
+      Point::new([2, 2]),
+      Point::new([5, 1]),
+      Point::new([5, 1]),
+    ];
+    let poly = convex_hull(points).unwrap();
+    assert_ok!(poly.validate());
+  }
+
+  #[test]
+  fn convex_hull_insufficient() {
+    let points: Vec<Point<i32>> = vec![];
+    assert_eq!(convex_hull(points).err(), Some(Error::InsufficientVertices));
+    let points = [Point::new([0, 0]), Point::new([2, 2])].repeat(3);
+    assert_eq!(convex_hull(points).err(), Some(Error::InsufficientVertices));
+    let points = vec![Point::new([0, 0]), Point::new([1, 1]), Point::new([2, 2])];
+    assert_eq!(convex_hull(points).err(), Some(Error::InsufficientVertices));
+  }
+
+  fn same_hull(pts: Vec<Point<i64>>) -> Result<(), TestCaseError> {
+    match (
+      convex_hull(pts.clone()),
+      graham_scan::convex_hull(pts.clone()),
+    ) {
+      (Ok(quick), Ok(graham)) => {
+        let quick: Vec<&Point<i64>> = quick.iter().collect();
+        let graham: Vec<&Point<i64>> = graham.iter().collect();
+        prop_assert_eq!(quick, graham);
+      }
+      (quick, graham) => prop_assert_eq!(quick.err(), graham.err()),
+    }
+    Ok(())
+  }
+
+  #[proptest]
+  fn matches_graham_scan_prop(#[strategy(vec(any::<[i32; 2]>(), 0..100))] pts: Vec<[i32; 2]>) {
+    same_hull(
+      pts
+        .into_iter()
+        .map(|p| Point::new(p.map(i64::from)))
+        .collect(),
+    )?;
+  }
+
+  // Small coordinates give many duplicate and colinear points.
+  #[proptest]
+  fn matches_graham_scan_small_prop(#[strategy(vec(any::<[i8; 2]>(), 0..100))] pts: Vec<[i8; 2]>) {
+    same_hull(
+      pts
+        .into_iter()
+        .map(|p| Point::new(p.map(|v| i64::from(v % 4))))
+        .collect(),
+    )?;
+  }
+
+  #[proptest]
+  fn convex_hull_prop_i8(#[strategy(vec(any::<[i8; 2]>(), 0..100))] pts: Vec<[i8; 2]>) {
+    let pts: Vec<Point<i16>> = pts
+      .into_iter()
+      .map(|p| Point::new(p.map(i16::from)))
+      .collect();
+    if let Ok(poly) = convex_hull(pts.clone()) {
+      prop_assert_eq!(poly.validate().err(), None);
+      for pt in pts.iter() {
+        prop_assert_ne!(poly.locate(pt), PointLocation::Outside)
+      }
+    }
+  }
+}
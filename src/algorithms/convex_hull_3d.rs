@@ -0,0 +1,250 @@
+use std::collections::BTreeSet;
+
+use crate::data::{Point, PointId};
+use crate::{Error, Orientation, PolygonScalar};
+
+/// Convex hull of a set of points in 3D.
+///
+/// Points are inserted one at a time. Faces visible from a new point are
+/// removed and the hole is closed with faces connecting the point to the
+/// horizon. Faces are returned as triangles of indices into `pts` and are
+/// oriented counter-clockwise when seen from outside the hull. Faces of the
+/// hull that aren't triangles are split into several coplanar triangles.
+///
+/// # Errors
+/// Returns [`Error::InsufficientVertices`] if there are fewer than four
+/// points or if all points are identical. Returns
+/// [`Error::CoLinearViolation`] if all points are colinear or coplanar.
+///
+/// # Properties
+/// * No points from the input set will be outside the returned hull.
+/// * Each edge is shared by exactly two faces.
+///
+/// # Time complexity
+/// $O(n^2)$
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::algorithms::convex_hull_3d::convex_hull;
+/// # use rgeometry::data::Point;
+/// let tetrahedron = vec![
+///   Point::new([0, 0, 0]),
+///   Point::new([1, 0, 0]),
+///   Point::new([0, 1, 0]),
+///   Point::new([0, 0, 1]),
+/// ];
+/// assert_eq!(convex_hull(tetrahedron).unwrap().len(), 4);
+/// ```
+pub fn convex_hull<T>(pts: Vec<Point<T, 3>>) -> Result<Vec<[PointId; 3]>, Error>
+where
+  T: PolygonScalar,
+{
+  let orient = |[a, b, c]: [usize; 3], d: usize| {
+    Orientation::orient3d(&pts[a].array, &pts[b].array, &pts[c].array, &pts[d].array)
+  };
+  let [a, b, c, d] = initial_tetrahedron(&pts)?;
+  // Orient the faces of the tetrahedron such that the remaining vertex is
+  // behind them.
+  let mut faces: Vec<[usize; 3]> = [[a, b, c, d], [a, b, d, c], [a, c, d, b], [b, c, d, a]]
+    .into_iter()
+    .map(|[p, q, r, s]| {
+      if orient([p, q, r], s).is_cw() {
+        [p, q, r]
+      } else {
+        [p, r, q]
+      }
+    })
+    .collect();
+
+  for pt in 0..pts.len() {
+    if [a, b, c, d].contains(&pt) || !faces.iter().any(|&face| orient(face, pt).is_ccw()) {
+      // Inside or on the hull.
+      continue;
+    }
+    // Faces coplanar with the new point are replaced as well. This guarantees
+    // that the new faces are never degenerate.
+    let (visible, hidden): (Vec<[usize; 3]>, Vec<[usize; 3]>) = faces
+      .into_iter()
+      .partition(|&face| !orient(face, pt).is_cw());
+    let edges: BTreeSet<(usize, usize)> = visible
+      .iter()
+      .flat_map(|&[p, q, r]| [(p, q), (q, r), (r, p)])
+      .collect();
+    faces = hidden;
+    for &(p, q) in &edges {
+      if !edges.contains(&(q, p)) {
+        faces.push([p, q, pt]);
+      }
+    }
+  }
+  Ok(faces.into_iter().map(|face| face.map(PointId)).collect())
+}
+
+// Find four points that are not coplanar.
+fn initial_tetrahedron<T>(pts: &[Point<T, 3>]) -> Result<[usize; 4], Error>
+where
+  T: PolygonScalar,
+{
+  if pts.len() < 4 {
+    return Err(Error::InsufficientVertices);
+  }
+  let a = 0;
+  let b = (1..pts.len())
+    .find(|&i| pts[i] != pts[a])
+    .ok_or(Error::InsufficientVertices)?;
+  let c = (1..pts.len())
+    .find(|&i| !colinear(&pts[a], &pts[b], &pts[i]))
+    .ok_or(Error::CoLinearViolation)?;
+  let d = (1..pts.len())
+    .find(|&i| {
+      !Orientation::orient3d(&pts[a].array, &pts[b].array, &pts[c].array, &pts[i].array)
+        .is_colinear()
+    })
+    .ok_or(Error::CoLinearViolation)?;
+  Ok([a, b, c, d])
+}
+
+// Three points are colinear in 3D iff they are colinear in every axis-aligned
+// projection.
+fn colinear<T>(p: &Point<T, 3>, q: &Point<T, 3>, r: &Point<T, 3>) -> bool
+where
+  T: PolygonScalar,
+{
+  let project = |pt: &Point<T, 3>, i: usize, j: usize| [pt.array[i].clone(), pt.array[j].clone()];
+  [(0, 1), (1, 2), (0, 2)].into_iter().all(|(i, j)| {
+    Orientation::new(&project(p, i, j), &project(q, i, j), &project(r, i, j)).is_colinear()
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  fn cube() -> Vec<Point<i32, 3>> {
+    let mut pts = Vec::new();
+    for x in [0, 1] {
+      for y in [0, 1] {
+        for z in [0, 1] {
+          pts.push(Point::new([x, y, z]));
+        }
+      }
+    }
+    pts
+  }
+
+  fn orient<T: PolygonScalar>(
+    pts: &[Point<T, 3>],
+    face: &[PointId; 3],
+    pt: &Point<T, 3>,
+  ) -> Orientation {
+    let [a, b, c] = face.map(|pid| &pts[pid.usize()].array);
+    Orientation::orient3d(a, b, c, &pt.array)
+  }
+
+  // Every directed edge appears exactly once and its twin is also present.
+  fn is_closed(faces: &[[PointId; 3]]) -> bool {
+    let mut edges = BTreeSet::new();
+    for &[p, q, r] in faces {
+      for edge in [(p, q), (q, r), (r, p)] {
+        if !edges.insert(edge) {
+          return false;
+        }
+      }
+    }
+    edges.iter().all(|&(p, q)| edges.contains(&(q, p)))
+  }
+
+  #[test]
+  fn cube_faces() {
+    let pts = cube();
+    let faces = convex_hull(pts.clone()).unwrap();
+    assert_eq!(faces.len(), 12);
+    assert!(is_closed(&faces));
+    for face in &faces {
+      for pt in &pts {
+        assert_ne!(orient(&pts, face, pt), Orientation::CounterClockWise);
+      }
+    }
+  }
+
+  #[test]
+  fn cube_interior_points() {
+    let mut pts: Vec<Point<i32, 3>> = cube().into_iter().map(|pt| pt.map(|v| v * 4)).collect();
+    pts.push(Point::new([2, 2, 2]));
+    pts.push(Point::new([1, 3, 2]));
+    pts.push(Point::new([2, 2, 4]));
+    let faces = convex_hull(pts).unwrap();
+    assert_eq!(faces.len(), 12);
+    assert!(faces.iter().flatten().all(|pid| pid.usize() < 8));
+  }
+
+  #[test]
+  fn outward_faces() {
+    let pts = vec![
+      Point::new([0, 0, 0]),
+      Point::new([1, 0, 0]),
+      Point::new([0, 1, 0]),
+      Point::new([0, 0, 1]),
+    ];
+    let faces = convex_hull(pts.clone()).unwrap();
+    assert_eq!(faces.len(), 4);
+    let outside = Point::new([-1, -1, -1]);
+    let inside = Point::new([1, 1, 1]);
+    // The face on the xy-plane is seen counter-clockwise from below.
+    let bottom = faces
+      .iter()
+      .find(|face| face.iter().all(|pid| pts[pid.usize()].array[2] == 0))
+      .unwrap();
+    assert_eq!(
+      orient(&pts, bottom, &outside),
+      Orientation::CounterClockWise
+    );
+    assert_eq!(orient(&pts, bottom, &inside), Orientation::ClockWise);
+  }
+
+  #[test]
+  fn degenerate_inputs() {
+    let pts: Vec<Point<i32, 3>> = vec![Point::new([0, 0, 0]); 5];
+    assert_eq!(convex_hull(pts).err(), Some(Error::InsufficientVertices));
+    let pts = cube()[..3].to_vec();
+    assert_eq!(convex_hull(pts).err(), Some(Error::InsufficientVertices));
+    let pts: Vec<Point<i32, 3>> = (0..5).map(|i| Point::new([i, 2 * i, 3 * i])).collect();
+    assert_eq!(convex_hull(pts).err(), Some(Error::CoLinearViolation));
+    let pts: Vec<Point<i32, 3>> = (0..9).map(|i| Point::new([i % 3, i / 3, 7])).collect();
+    assert_eq!(convex_hull(pts).err(), Some(Error::CoLinearViolation));
+  }
+
+  #[proptest]
+  fn inside_or_on_prop(#[strategy(vec(any::<Point<i8, 3>>(), 4..30))] pts: Vec<Point<i8, 3>>) {
+    if let Ok(faces) = convex_hull(pts.clone()) {
+      prop_assert!(is_closed(&faces));
+      for face in &faces {
+        for pt in &pts {
+          prop_assert_ne!(orient(&pts, face, pt), Orientation::CounterClockWise);
+        }
+      }
+    }
+  }
+
+  #[proptest]
+  fn small_grid_prop(#[strategy(vec(any::<[bool; 3]>(), 4..30))] pts: Vec<[bool; 3]>) {
+    // Many coplanar and duplicate points.
+    let pts: Vec<Point<i8, 3>> = pts
+      .into_iter()
+      .map(|p| Point::new(p.map(i8::from)))
+      .collect();
+    if let Ok(faces) = convex_hull(pts.clone()) {
+      prop_assert!(is_closed(&faces));
+      for face in &faces {
+        for pt in &pts {
+          prop_assert_ne!(orient(&pts, face, pt), Orientation::CounterClockWise);
+        }
+      }
+    }
+  }
+}
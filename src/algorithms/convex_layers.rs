@@ -0,0 +1,92 @@
+use crate::algorithms::convex_hull;
+use crate::data::{Point, PointLocation, PolygonConvex};
+use crate::PolygonScalar;
+
+/// Convex layers (onion peeling) of a set of points, from the outermost to
+/// the innermost layer.
+///
+/// Each layer is the convex hull of the points that are strictly inside the
+/// previous layer. Points on the boundary of a hull belong to that layer even
+/// if they aren't vertices of it. Peeling stops when the remaining points
+/// don't span a polygon, that is when fewer than three remain or they are all
+/// colinear. Those points are not part of the output.
+///
+/// # Time complexity
+///
+/// $O(k n \log n)$ where $k$ is the number of layers.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// # use rgeometry::algorithms::convex_layers::convex_layers;
+/// let pts = vec![
+///   Point::new([0, 0]),
+///   Point::new([9, 0]),
+///   Point::new([0, 9]),
+///   Point::new([2, 2]),
+///   Point::new([4, 2]),
+///   Point::new([2, 4]),
+/// ];
+/// let layers = convex_layers(pts);
+/// assert_eq!(layers.len(), 2);
+/// assert_eq!(layers[1].iter().count(), 3);
+/// ```
+pub fn convex_layers<T: PolygonScalar>(mut points: Vec<Point<T, 2>>) -> Vec<PolygonConvex<T>> {
+  let mut layers = Vec::new();
+  while points.len() >= 3 {
+    let Ok(hull) = convex_hull(points.clone()) else {
+      break;
+    };
+    points.retain(|pt| hull.locate(pt) == PointLocation::Inside);
+    layers.push(hull);
+  }
+  layers
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  #[test]
+  fn grid() {
+    let pts: Vec<Point<i32>> = (0..3)
+      .flat_map(|x| (0..3).map(move |y| Point::new([x, y])))
+      .collect();
+    let layers = convex_layers(pts);
+    assert_eq!(layers.len(), 1);
+    let mut corners: Vec<Point<i32>> = layers[0].iter().cloned().collect();
+    corners.sort();
+    assert_eq!(
+      corners,
+      vec![
+        Point::new([0, 0]),
+        Point::new([0, 2]),
+        Point::new([2, 0]),
+        Point::new([2, 2]),
+      ]
+    );
+    // The center is strictly inside and too lonely to form a layer.
+    assert_eq!(layers[0].locate(&Point::new([1, 1])), PointLocation::Inside);
+  }
+
+  #[proptest]
+  fn nested_prop(#[strategy(vec(any::<Point<i8>>(), 0..100))] pts: Vec<Point<i8>>) {
+    let layers = convex_layers(pts.clone());
+    for pair in layers.windows(2) {
+      for pt in pair[1].iter() {
+        prop_assert_eq!(pair[0].locate(pt), PointLocation::Inside);
+      }
+    }
+    for layer in &layers {
+      prop_assert_eq!(layer.validate().err(), None);
+      for pt in layer.iter() {
+        prop_assert!(pts.contains(pt));
+      }
+    }
+  }
+}
@@ -1 +1,5 @@
 pub mod naive;
+pub mod sweep;
+
+#[doc(inline)]
+pub use sweep::crossing_points;
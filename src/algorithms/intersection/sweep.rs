@@ -0,0 +1,315 @@
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::ops::Bound::{Excluded, Unbounded};
+
+use num::BigRational;
+
+use crate::data::{EndPoint, ILineSegment, ILineSegmentExact, LineSegment, LineSegmentView, Point};
+use crate::{Intersects, Orientation, PolygonScalar, ToRational};
+
+/// Check if any two edges of a closed polyline intersect. Each edge includes
+/// its first point and excludes its last point, so neighbouring edges only
+/// intersect if they overlap. This matches
+/// [`segment_intersections`](super::naive::segment_intersections) applied to
+/// the directed edges of the polyline.
+///
+/// Uses the Shamos–Hoey sweep, the detection-only variant of Bentley–Ottmann,
+/// and stops at the first intersection.
+///
+/// # Time complexity
+/// $O(n \log n)$
+pub fn polyline_intersects<T: PolygonScalar>(ring: &[&Point<T>]) -> bool {
+  // Zero-length edges are empty and can be dropped.
+  let mut pts: Vec<&Point<T>> = Vec::with_capacity(ring.len());
+  for &pt in ring {
+    if pts.last() != Some(&pt) {
+      pts.push(pt);
+    }
+  }
+  while pts.len() > 1 && pts.first() == pts.last() {
+    pts.pop();
+  }
+  // Any remaining duplicate is the first point of two different edges.
+  let mut sorted = pts.clone();
+  sorted.sort_unstable();
+  if sorted.windows(2).any(|w| w[0] == w[1]) {
+    return true;
+  }
+  let n = pts.len();
+  if n < 3 {
+    return n == 2;
+  }
+
+  let edges: Vec<SweepEdge<'_, T>> = (0..n)
+    .map(|id| SweepEdge::new(id, pts[id], pts[(id + 1) % n]))
+    .collect();
+  // Events are (point, is_insertion, edge). Removals come before insertions.
+  let mut events: Vec<(&Point<T>, bool, usize)> = Vec::with_capacity(2 * n);
+  for edge in &edges {
+    events.push((edge.left, true, edge.id));
+    events.push((edge.right, false, edge.id));
+  }
+  events.sort_unstable_by(|a, b| a.0.cmp(b.0).then(a.1.cmp(&b.1)));
+
+  let mut status: BTreeSet<SweepEdge<'_, T>> = BTreeSet::new();
+  for (_, insertion, id) in events {
+    let edge = edges[id];
+    if insertion {
+      // Edges only compare equal if they touch.
+      if !status.insert(edge) {
+        return true;
+      }
+    } else {
+      status.remove(&edge);
+    }
+    let below = status.range(..edge).next_back();
+    let above = status.range((Excluded(edge), Unbounded)).next();
+    let conflict = if insertion {
+      below.is_some_and(|other| edge.touches(other, n))
+        || above.is_some_and(|other| edge.touches(other, n))
+    } else {
+      matches!((below, above), (Some(a), Some(b)) if a.touches(b, n))
+    };
+    if conflict {
+      return true;
+    }
+  }
+  false
+}
+
+/// Every point where two of the segments meet: proper crossings, touching
+/// endpoints and both ends of overlapping parts. Points are found exactly,
+/// deduplicated and then rounded, and come out in lexicographic order.
+///
+/// A sweep from left to right only tests segments whose horizontal extents
+/// overlap.
+///
+/// # Time complexity
+/// $O(n \log n + m)$ where $m$ is the number of pairs of segments with
+/// overlapping horizontal extents.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// # use rgeometry::algorithms::intersection::crossing_points;
+/// let edges = vec![
+///   LineSegment::from(Point::new([0, 0])..Point::new([2, 2])),
+///   LineSegment::from(Point::new([0, 2])..Point::new([2, 0])),
+/// ];
+/// assert_eq!(crossing_points(&edges), vec![Point::new([1.0, 1.0])]);
+/// ```
+pub fn crossing_points<T: PolygonScalar + ToRational>(
+  edges: &[LineSegment<T>],
+) -> Vec<Point<f64, 2>> {
+  let left = |i: usize| edges[i].min.inner().x_coord();
+  let right = |i: usize| edges[i].max.inner().x_coord();
+  let mut order: Vec<usize> = (0..edges.len()).collect();
+  order.sort_by(|&a, &b| left(a).total_cmp(left(b)));
+  let mut points: BTreeSet<Point<BigRational>> = BTreeSet::new();
+  let mut active: Vec<usize> = Vec::new();
+  for i in order {
+    active.retain(|&j| right(j).total_cmp(left(i)).is_ge());
+    for &j in &active {
+      match edges[i]
+        .as_ref()
+        .intersection_point_exact(edges[j].as_ref())
+      {
+        Some(ILineSegmentExact::Crossing(pt)) => {
+          points.insert(pt);
+        }
+        Some(ILineSegmentExact::Overlap(overlap)) => {
+          points.insert(overlap.min.inner().clone());
+          points.insert(overlap.max.inner().clone());
+        }
+        None => {}
+      }
+    }
+    active.push(i);
+  }
+  // Rounding can merge distinct x coordinates and reorder the points, so sort
+  // again before removing duplicates.
+  let mut rounded: Vec<Point<f64>> = points.iter().map(Point::from).collect();
+  rounded.sort();
+  rounded.dedup();
+  rounded
+}
+
+// Edge of the polyline with its endpoints in sweep order.
+#[derive(Debug)]
+struct SweepEdge<'a, T> {
+  id: usize,
+  left: &'a Point<T>,
+  right: &'a Point<T>,
+}
+
+impl<T> Copy for SweepEdge<'_, T> {}
+impl<T> Clone for SweepEdge<'_, T> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<'a, T: PolygonScalar> SweepEdge<'a, T> {
+  fn new(id: usize, a: &'a Point<T>, b: &'a Point<T>) -> Self {
+    let (left, right) = if a < b { (a, b) } else { (b, a) };
+    SweepEdge { id, left, right }
+  }
+
+  // Position of `pt` relative to the line through the edge.
+  fn side(&self, pt: &Point<T>) -> Ordering {
+    match Point::orient(self.left, self.right, pt) {
+      Orientation::CounterClockWise => Ordering::Greater,
+      Orientation::ClockWise => Ordering::Less,
+      Orientation::CoLinear => Ordering::Equal,
+    }
+  }
+
+  // Closed edges touch. Neighbouring edges may share their common vertex.
+  fn touches(&self, other: &Self, n: usize) -> bool {
+    let segment = |edge: &Self| {
+      LineSegmentView::new(
+        EndPoint::Inclusive(edge.left),
+        EndPoint::Inclusive(edge.right),
+      )
+    };
+    let neighbours = (self.id + 1) % n == other.id || (other.id + 1) % n == self.id;
+    match segment(self).intersect(segment(other)) {
+      None => false,
+      Some(ILineSegment::Crossing) => !neighbours,
+      Some(ILineSegment::Overlap(overlap)) => {
+        !neighbours || overlap.min.inner() != overlap.max.inner()
+      }
+    }
+  }
+}
+
+// Vertical order of edges that are both cut by the sweep line. Touching edges
+// compare equal.
+impl<T: PolygonScalar> Ord for SweepEdge<'_, T> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    if self.id == other.id {
+      return Ordering::Equal;
+    }
+    match self.left.cmp(other.left) {
+      Ordering::Equal => other.side(self.right),
+      Ordering::Greater => other.side(self.left),
+      Ordering::Less => self.side(other.left).reverse(),
+    }
+  }
+}
+
+impl<T: PolygonScalar> PartialOrd for SweepEdge<'_, T> {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<T: PolygonScalar> PartialEq for SweepEdge<'_, T> {
+  fn eq(&self, other: &Self) -> bool {
+    self.cmp(other) == Ordering::Equal
+  }
+}
+
+impl<T: PolygonScalar> Eq for SweepEdge<'_, T> {}
+
+#[cfg(test)]
+#[cfg(not(tarpaulin_include))]
+mod tests {
+  use super::*;
+  use crate::data::DirectedEdge;
+
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  fn naive(pts: &[Point<i32>]) -> bool {
+    let n = pts.len();
+    let edges: Vec<DirectedEdge<'_, i32>> = (0..n)
+      .map(|i| DirectedEdge {
+        src: &pts[i],
+        dst: &pts[(i + 1) % n],
+      })
+      .collect();
+    let mut isects = crate::algorithms::segment_intersections(&edges);
+    isects.next().is_some()
+  }
+
+  #[test]
+  fn touching_vertex() {
+    let pts: Vec<Point<i32>> = [[0, 0], [4, 0], [2, 2], [4, 4], [0, 4], [2, 0]]
+      .into_iter()
+      .map(Point::new)
+      .collect();
+    let ring: Vec<&Point<i32>> = pts.iter().collect();
+    assert!(polyline_intersects(&ring));
+    assert!(!polyline_intersects(&ring[..5]));
+  }
+
+  #[test]
+  fn grid_crossings() {
+    let segment = |a: [i32; 2], b: [i32; 2]| LineSegment::from(Point::new(a)..Point::new(b));
+    let edges = vec![
+      segment([0, 1], [3, 1]),
+      segment([0, 2], [3, 2]),
+      segment([1, 0], [1, 3]),
+      segment([2, 0], [2, 3]),
+    ];
+    assert_eq!(
+      crossing_points(&edges),
+      vec![
+        Point::new([1.0, 1.0]),
+        Point::new([1.0, 2.0]),
+        Point::new([2.0, 1.0]),
+        Point::new([2.0, 2.0]),
+      ]
+    );
+  }
+
+  #[test]
+  fn overlap_endpoints() {
+    let segment = |a: [i32; 2], b: [i32; 2]| LineSegment::from(Point::new(a)..Point::new(b));
+    let edges = vec![
+      segment([0, 0], [4, 0]),
+      segment([2, 0], [6, 0]),
+      segment([3, -1], [3, 1]),
+    ];
+    assert_eq!(
+      crossing_points(&edges),
+      vec![
+        Point::new([2.0, 0.0]),
+        Point::new([3.0, 0.0]),
+        Point::new([4.0, 0.0]),
+      ]
+    );
+  }
+
+  #[test]
+  fn rounding_merges_points() {
+    // 2^53 + 1 isn't representable as f64 and rounds down to 2^53.
+    let n = 1i64 << 53;
+    let segment = |a: [i64; 2], b: [i64; 2]| LineSegment::from(Point::new(a)..Point::new(b));
+    let edges = vec![
+      segment([n, -10], [n, 10]),
+      segment([n + 1, -10], [n + 1, 10]),
+      segment([n - 10, 0], [n + 10, 0]),
+      segment([n - 10, 5], [n + 10, 5]),
+    ];
+    let x = 2f64.powi(53);
+    assert_eq!(
+      crossing_points(&edges),
+      vec![Point::new([x, 0.0]), Point::new([x, 5.0])]
+    );
+  }
+
+  #[proptest]
+  fn naive_prop(#[strategy(vec(any::<[u8; 2]>(), 0..30))] pts: Vec<[u8; 2]>) {
+    // Small coordinates give plenty of colinear and touching edges.
+    let pts: Vec<Point<i32>> = pts
+      .into_iter()
+      .map(|[x, y]| Point::new([i32::from(x % 8), i32::from(y % 8)]))
+      .collect();
+    let ring: Vec<&Point<i32>> = pts.iter().collect();
+    prop_assert_eq!(polyline_intersects(&ring), naive(&pts));
+  }
+}
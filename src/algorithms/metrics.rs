@@ -0,0 +1,184 @@
+use num_traits::ToPrimitive;
+
+use crate::algorithms::boolean::{Operation, Overlay};
+use crate::data::{Point, Polygon};
+use crate::PolygonScalar;
+
+///////////////////////////////////////////////////////////////////////////////
+// Discrete Fréchet distance
+
+/// Discrete Fréchet distance between two polylines.
+///
+/// The distance is the smallest leash length that allows two walkers to
+/// traverse `p` and `q` from start to end, where each walker may only stay put
+/// or advance one vertex at a time. Distances between vertices are Euclidean.
+///
+/// # Panics
+///
+/// Panics if either polyline is empty.
+///
+/// # Time complexity
+///
+/// $O(nm)$
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::Point;
+/// # use rgeometry::algorithms::metrics::discrete_frechet;
+/// let p = [Point::new([0, 0]), Point::new([4, 0])];
+/// let q = [Point::new([0, 3]), Point::new([4, 3])];
+/// assert_eq!(discrete_frechet(&p, &q), 3.0);
+/// ```
+pub fn discrete_frechet<T>(p: &[Point<T, 2>], q: &[Point<T, 2>]) -> f64
+where
+  T: PolygonScalar + ToPrimitive,
+{
+  assert!(
+    !p.is_empty() && !q.is_empty(),
+    "discrete_frechet requires non-empty polylines"
+  );
+  let p: Vec<Point<f64, 2>> = p.iter().map(Point::to_f64).collect();
+  let q: Vec<Point<f64, 2>> = q.iter().map(Point::to_f64).collect();
+  let dist = |a: &Point<f64, 2>, b: &Point<f64, 2>| a.squared_euclidean_distance::<f64>(b).sqrt();
+
+  // Coupling table, one row at a time. row[j] is the coupling distance
+  // between p[..=i] and q[..=j].
+  let mut row: Vec<f64> = Vec::with_capacity(q.len());
+  for (j, b) in q.iter().enumerate() {
+    let d = dist(&p[0], b);
+    row.push(if j == 0 { d } else { d.max(row[j - 1]) });
+  }
+  for a in &p[1..] {
+    let mut diag = row[0];
+    row[0] = row[0].max(dist(a, &q[0]));
+    for (j, b) in q.iter().enumerate().skip(1) {
+      let best = diag.min(row[j]).min(row[j - 1]);
+      diag = row[j];
+      row[j] = best.max(dist(a, b));
+    }
+  }
+  row[q.len() - 1]
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Symmetric difference
+
+/// Area of the symmetric difference of two simple polygons.
+///
+/// This is the area covered by exactly one of the polygons. It is computed
+/// from the overlay of the two boundaries without building the boolean
+/// results: the area enclosed by the edges of `a - b` and `b - a` is summed
+/// directly.
+///
+/// Crossing points are computed with the scalar type, see
+/// [`boolean::union`](crate::algorithms::boolean::union).
+///
+/// # Panics
+///
+/// Panics if either polygon has holes.
+///
+/// # Time complexity
+///
+/// $O(nm(n+m))$
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// # use rgeometry::algorithms::metrics::symmetric_difference_area;
+/// # fn main() -> Result<(), rgeometry::Error> {
+/// let a = Polygon::new(vec![
+///   Point::new([0, 0]),
+///   Point::new([2, 0]),
+///   Point::new([2, 2]),
+///   Point::new([0, 2]),
+/// ])?;
+/// assert_eq!(symmetric_difference_area(&a, &a), 0.0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn symmetric_difference_area<T>(a: &Polygon<T>, b: &Polygon<T>) -> f64
+where
+  T: PolygonScalar + ToPrimitive,
+{
+  let edges = Overlay::new(a, b).edges(Operation::SymmetricDifference);
+  let area_2x: f64 = edges
+    .iter()
+    .map(|(src, dst)| {
+      let [x1, y1] = src.to_f64().array;
+      let [x2, y2] = dst.to_f64().array;
+      x1 * y2 - x2 * y1
+    })
+    .sum();
+  area_2x / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::testing::square;
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  #[test]
+  fn parallel_segments() {
+    let p = [Point::new([0, 0]), Point::new([5, 0])];
+    let q = [Point::new([0, 4]), Point::new([5, 4])];
+    assert_eq!(discrete_frechet(&p, &q), 4.0);
+    // The extra vertex at (2,0) has to be coupled with one of the endpoints of 'q'.
+    let p = [Point::new([0, 0]), Point::new([2, 0]), Point::new([5, 0])];
+    assert_eq!(discrete_frechet(&p, &q), 20f64.sqrt());
+    assert_eq!(discrete_frechet(&q, &p), 20f64.sqrt());
+  }
+
+  #[test]
+  fn single_point() {
+    let p = [Point::new([0, 0])];
+    let q = [Point::new([3, 4]), Point::new([1, 0]), Point::new([0, 2])];
+    assert_eq!(discrete_frechet(&p, &q), 5.0);
+  }
+
+  #[test]
+  fn symmetric_difference_extremes() {
+    let a = square(0, 0, 4);
+    let b = square(10, 0, 3);
+    assert_eq!(symmetric_difference_area(&a, &a), 0.0);
+    assert_eq!(symmetric_difference_area(&a, &b), 25.0);
+    // Nested polygons.
+    let c = square(1, 1, 2);
+    assert_eq!(symmetric_difference_area(&a, &c), 12.0);
+    assert_eq!(symmetric_difference_area(&c, &a), 12.0);
+  }
+
+  #[test]
+  fn symmetric_difference_partial_overlap() {
+    // 4x4 and 3x3 squares overlapping in a 2x1 rectangle: 16 + 9 - 2 * 2.
+    let a = square(0, 0, 4);
+    let b = square(2, 3, 3);
+    assert_eq!(symmetric_difference_area(&a, &b), 21.0);
+    // Squares sharing an edge don't overlap.
+    let c = square(4, 0, 4);
+    assert_eq!(symmetric_difference_area(&a, &c), 32.0);
+  }
+
+  #[proptest]
+  fn symmetric_difference_identical_prop(poly: Polygon<i8>) {
+    prop_assert_eq!(symmetric_difference_area(&poly, &poly), 0.0);
+  }
+
+  #[proptest]
+  fn identical_prop(#[strategy(vec(any::<Point<i8>>(), 1..20))] p: Vec<Point<i8>>) {
+    prop_assert_eq!(discrete_frechet(&p, &p), 0.0);
+  }
+
+  #[proptest]
+  fn symmetric_prop(
+    #[strategy(vec(any::<Point<i8>>(), 1..20))] p: Vec<Point<i8>>,
+    #[strategy(vec(any::<Point<i8>>(), 1..20))] q: Vec<Point<i8>>,
+  ) {
+    prop_assert_eq!(discrete_frechet(&p, &q), discrete_frechet(&q, &p));
+  }
+}
@@ -0,0 +1,329 @@
+use num_traits::ToPrimitive;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+use std::collections::BTreeSet;
+
+use crate::algorithms::polygonization::resolve_self_intersections;
+use crate::data::{Point, Polygon, Vector};
+use crate::{Error, Orientation, PolygonScalar};
+
+/// Offset the boundary of a polygon by a fixed distance.
+///
+/// Each edge is moved `delta` units along its normal, outwards for positive
+/// values and inwards for negative values. Adjacent offset edges are extended
+/// until they meet (miter joins). Edges that collapse are dropped and any
+/// remaining self-intersections are removed with 2-opt moves.
+///
+/// Insetting by more than the inradius leaves nothing and yields an empty
+/// vector.
+///
+/// # Panics
+///
+/// Panics if the polygon has holes.
+///
+/// # Time complexity
+///
+/// $O(n^2)$ unless self-intersections have to be resolved.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// # use rgeometry::algorithms::offset::offset_polygon;
+/// # fn main() -> Result<(), rgeometry::Error> {
+/// let square = Polygon::new(vec![
+///   Point::new([0, 0]),
+///   Point::new([10, 0]),
+///   Point::new([10, 10]),
+///   Point::new([0, 10]),
+/// ])?;
+/// let inset = offset_polygon(&square, -2.0);
+/// assert_eq!(inset[0].signed_area::<f64>(), 36.0);
+/// assert!(offset_polygon(&square, -6.0).is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub fn offset_polygon<T>(poly: &Polygon<T>, delta: f64) -> Vec<Polygon<f64>>
+where
+  T: PolygonScalar + ToPrimitive,
+{
+  assert_eq!(poly.rings.len(), 1, "offset_polygon does not support holes");
+  let pts: Vec<Point<f64>> = poly.iter_boundary().map(|c| c.point().to_f64()).collect();
+  let n = pts.len();
+  // Offset lines given by a point and the direction of the original edge.
+  let mut lines: Vec<(Point<f64>, Vector<f64, 2>)> = (0..n)
+    .filter_map(|i| {
+      let src = &pts[i];
+      let direction = &pts[(i + 1) % n] - src;
+      let len = direction.squared_magnitude().sqrt();
+      if len == 0.0 {
+        return None;
+      }
+      let normal = Vector([direction.0[1] / len, -direction.0[0] / len]);
+      Some((src + &(normal * delta), direction))
+    })
+    .collect();
+
+  // Drop edges that turn around after offsetting until every edge keeps its
+  // original direction.
+  let vertices = loop {
+    if lines.len() < 3 {
+      return vec![];
+    }
+    let vertices = miter_vertices(&lines);
+    let m = lines.len();
+    let collapsed = (0..m).find(|&i| {
+      let edge = vertices[(i + 1) % m] - vertices[i];
+      dot(&edge, &lines[i].1) <= 0.0
+    });
+    match collapsed {
+      Some(i) => {
+        lines.remove(i);
+      }
+      None => break vertices,
+    }
+  };
+
+  let mut seen = BTreeSet::new();
+  let vertices: Vec<Point<f64>> = vertices
+    .into_iter()
+    .filter(|pt| pt.array.iter().all(|v| v.is_finite()) && seen.insert(*pt))
+    .collect();
+  if vertices.len() < 3 {
+    return vec![];
+  }
+  let mut offset = Polygon::new_unchecked(vertices);
+  match offset.validate() {
+    Ok(()) => vec![offset],
+    Err(Error::SelfIntersections) => {
+      let rng = &mut SmallRng::seed_from_u64(0);
+      match resolve_self_intersections(&mut offset, rng) {
+        Ok(()) => vec![offset],
+        Err(_) => vec![],
+      }
+    }
+    Err(_) => vec![],
+  }
+}
+
+/// Minkowski sum of a polygon and a disk, that is every point within
+/// `radius` of the polygon.
+///
+/// Edges are moved `radius` units outwards. At convex vertices the offset
+/// edges are joined by an arc that is approximated with `arc_segments`
+/// segments. At reflex vertices they are extended until they meet. The
+/// result is only simple if no two offset edges collide, which always holds
+/// for convex polygons.
+///
+/// # Panics
+///
+/// Panics if the polygon has holes, if `radius` isn't positive or if
+/// `arc_segments` is zero.
+///
+/// # Time complexity
+///
+/// $O(n k)$ where $k$ is `arc_segments`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// # use rgeometry::algorithms::offset::buffer_rounded;
+/// let square = Polygon::new(vec![
+///   Point::new([0, 0]),
+///   Point::new([2, 0]),
+///   Point::new([2, 2]),
+///   Point::new([0, 2]),
+/// ]).unwrap();
+/// let buffer = buffer_rounded(&square, 1.0, 1);
+/// // Every corner is cut off by a single chamfer.
+/// assert_eq!(buffer.iter().count(), 8);
+/// assert_eq!(buffer.signed_area::<f64>(), 14.0);
+/// ```
+pub fn buffer_rounded<T>(poly: &Polygon<T>, radius: f64, arc_segments: usize) -> Polygon<f64>
+where
+  T: PolygonScalar + ToPrimitive,
+{
+  assert_eq!(poly.rings.len(), 1, "buffer_rounded does not support holes");
+  assert!(radius > 0.0, "buffer radius must be positive");
+  assert!(arc_segments > 0, "arcs need at least one segment");
+  let outward = |src: &Point<f64>, dst: &Point<f64>| {
+    let direction = dst - src;
+    let len = direction.squared_magnitude().sqrt();
+    Vector([direction.0[1] / len, -direction.0[0] / len])
+  };
+  let mut vertices = Vec::new();
+  for cursor in poly.iter_boundary() {
+    let prev = cursor.prev().point().to_f64();
+    let here = cursor.point().to_f64();
+    let next = cursor.next().point().to_f64();
+    let normal_in = outward(&prev, &here);
+    let normal_out = outward(&here, &next);
+    match cursor.orientation() {
+      Orientation::CounterClockWise => {
+        let angle = cross(&normal_in, &normal_out).atan2(dot(&normal_in, &normal_out));
+        let step = angle / arc_segments.to_f64().unwrap();
+        for k in 0..=arc_segments {
+          let normal = normal_in.rotate(step * k.to_f64().unwrap());
+          vertices.push(here + &(normal * radius));
+        }
+      }
+      Orientation::CoLinear => vertices.push(here + &(normal_in * radius)),
+      Orientation::ClockWise => {
+        let lines = [
+          (here + &(normal_in * radius), here - prev),
+          (here + &(normal_out * radius), next - here),
+        ];
+        vertices.push(miter_vertices(&lines)[1]);
+      }
+    }
+  }
+  Polygon::new_unchecked(vertices)
+}
+
+// Vertex i is where line i-1 meets line i.
+fn miter_vertices(lines: &[(Point<f64>, Vector<f64, 2>)]) -> Vec<Point<f64>> {
+  let m = lines.len();
+  (0..m)
+    .map(|i| {
+      let (p, u) = &lines[(i + m - 1) % m];
+      let (q, v) = &lines[i];
+      let denom = cross(u, v);
+      if denom.abs() <= f64::EPSILON * u.squared_magnitude().sqrt() * v.squared_magnitude().sqrt() {
+        // Parallel edges meet at the offset of their shared vertex.
+        *q
+      } else {
+        let t = cross(&(q - p), v) / denom;
+        p + &(u.clone() * t)
+      }
+    })
+    .collect()
+}
+
+fn cross(u: &Vector<f64, 2>, v: &Vector<f64, 2>) -> f64 {
+  u.0[0] * v.0[1] - u.0[1] * v.0[0]
+}
+
+fn dot(u: &Vector<f64, 2>, v: &Vector<f64, 2>) -> f64 {
+  u.0[0] * v.0[0] + u.0[1] * v.0[1]
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::data::PolygonConvex;
+  use crate::testing::square;
+
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  #[test]
+  fn square_inset() {
+    let inset = offset_polygon(&square(0, 0, 10), -2.0);
+    assert_eq!(inset.len(), 1);
+    assert_eq!(inset[0].signed_area::<f64>(), 36.0);
+    let expected = Polygon::new(vec![
+      Point::new([2.0, 2.0]),
+      Point::new([8.0, 2.0]),
+      Point::new([8.0, 8.0]),
+      Point::new([2.0, 8.0]),
+    ])
+    .unwrap();
+    assert!(inset[0].equals(&expected));
+  }
+
+  #[test]
+  fn square_outset() {
+    let outset = offset_polygon(&square(0, 0, 10), 1.0);
+    assert_eq!(outset.len(), 1);
+    assert_eq!(outset[0].signed_area::<f64>(), 144.0);
+    // Each side grows by 2 in extent.
+    let (min, max) = outset[0].bounding_box();
+    assert_eq!(min, Point::new([-1.0, -1.0]));
+    assert_eq!(max, Point::new([11.0, 11.0]));
+  }
+
+  #[test]
+  fn square_inset_past_inradius() {
+    assert!(offset_polygon(&square(0, 0, 10), -5.5).is_empty());
+    assert!(offset_polygon(&square(0, 0, 10), -20.0).is_empty());
+  }
+
+  #[test]
+  fn l_shape_inset() {
+    let poly = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([4, 0]),
+      Point::new([4, 1]),
+      Point::new([1, 1]),
+      Point::new([1, 4]),
+      Point::new([0, 4]),
+    ])
+    .unwrap();
+    let inset = offset_polygon(&poly, -0.25);
+    assert_eq!(inset.len(), 1);
+    assert!((inset[0].signed_area::<f64>() - 3.25).abs() < 1e-9);
+  }
+
+  #[test]
+  fn colinear_edges() {
+    let poly = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([5, 0]),
+      Point::new([10, 0]),
+      Point::new([10, 10]),
+      Point::new([0, 10]),
+    ])
+    .unwrap();
+    let inset = offset_polygon(&poly, -2.0);
+    assert_eq!(inset.len(), 1);
+    assert_eq!(inset[0].signed_area::<f64>(), 36.0);
+  }
+
+  #[test]
+  fn square_buffer_area() {
+    // Each side gains a 10 by 1 strip and the corners add up to a unit disk.
+    let expected = 100.0 + 4.0 * 10.0 + std::f64::consts::PI;
+    let coarse = buffer_rounded(&square(0, 0, 10), 1.0, 4).signed_area::<f64>();
+    let fine = buffer_rounded(&square(0, 0, 10), 1.0, 256).signed_area::<f64>();
+    assert!(coarse < fine && fine < expected);
+    assert!(expected - fine < 1e-4);
+  }
+
+  #[test]
+  fn l_shape_buffer() {
+    let poly = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([4, 0]),
+      Point::new([4, 1]),
+      Point::new([1, 1]),
+      Point::new([1, 4]),
+      Point::new([0, 4]),
+    ])
+    .unwrap();
+    let buffer = buffer_rounded(&poly, 0.5, 8);
+    assert_eq!(buffer.validate().err(), None);
+    // Five convex corners with arcs and a single mitered reflex corner.
+    assert_eq!(buffer.iter().count(), 5 * 9 + 1);
+    assert!(buffer.iter().any(|pt| pt == &Point::new([1.5, 1.5])));
+  }
+
+  #[proptest]
+  fn buffer_valid_prop(#[any(3..12)] poly: PolygonConvex<i8>, #[strategy(0.1..10.0)] radius: f64) {
+    let buffer = buffer_rounded(&poly, radius, 4);
+    prop_assert_eq!(buffer.validate().err(), None);
+    for pt in poly.iter() {
+      prop_assert_eq!(
+        buffer.locate(&pt.to_f64()),
+        crate::data::PointLocation::Inside
+      );
+    }
+  }
+
+  #[proptest]
+  fn offset_valid_prop(poly: Polygon<i8>, #[strategy(-10.0..10.0)] delta: f64) {
+    for offset in offset_polygon(&poly, delta) {
+      prop_assert_eq!(offset.validate().err(), None);
+    }
+  }
+}
@@ -5,4 +5,7 @@ mod two_opt;
 pub use monotone::new_monotone_polygon;
 pub use star::new_star_polygon;
 pub use two_opt::resolve_self_intersections;
+#[cfg(feature = "rayon")]
+pub use two_opt::resolve_self_intersections_par;
 pub use two_opt::two_opt_moves;
+pub use two_opt::two_opt_moves_from;
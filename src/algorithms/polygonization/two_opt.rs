@@ -15,39 +15,247 @@ use std::ops::Bound::*;
 
 use crate::Orientation;
 
+/// Remove self-intersections by repeatedly uncrossing intersecting edges.
+///
+/// Large polygons bucket their edges in a grid so that intersection tests
+/// only consider edges whose bounding boxes share a cell.
+///
+/// # Time complexity
+/// $O(n^2)$ for finding the initial intersections plus $O(n)$ for each
+/// untangling step.
 pub fn resolve_self_intersections<T, R>(poly: &mut Polygon<T>, rng: &mut R) -> Result<(), Error>
 where
   T: PolygonScalar,
   R: Rng + ?Sized,
 {
+  let grid = poly.iter_boundary().len() >= GRID_THRESHOLD;
+  resolve_with(poly, rng, grid)
+}
+
+// Polygons with fewer vertices test every pair of edges.
+const GRID_THRESHOLD: usize = 32;
+
+fn resolve_with<T, R>(poly: &mut Polygon<T>, rng: &mut R, grid: bool) -> Result<(), Error>
+where
+  T: PolygonScalar,
+  R: Rng + ?Sized,
+{
+  check_not_colinear(poly)?;
+  // Edges are undirected so the list only changes where edges are uncrossed.
+  let edge_index = EdgeIndex::new(poly, grid);
+  let mut found = Vec::new();
+  match &edge_index.grid {
+    None => {
+      let edge_list = &edge_index.list;
+      for (i, &e1) in edge_list.iter().enumerate() {
+        for &e2 in &edge_list[i + 1..] {
+          if let Some(isect) = intersects(poly, e1, e2) {
+            found.push(isect)
+          }
+        }
+      }
+    }
+    Some(grid) => {
+      for &e1 in &edge_index.list {
+        for e2 in grid.candidates(e1) {
+          if e1 < e2 {
+            if let Some(isect) = intersects(poly, e1, e2) {
+              found.push(isect)
+            }
+          }
+        }
+      }
+    }
+  }
+  untangle_all(poly, rng, edge_index, found)
+}
+
+/// Same as [`resolve_self_intersections`] but the initial $O(n^2)$ scan for
+/// intersecting edges runs in parallel. The untangling steps are sequential
+/// and give the same result for the same random number generator.
+///
+/// # Time complexity
+/// $O(n^2/p)$ for finding the initial intersections plus $O(n)$ for each
+/// untangling step.
+#[cfg(feature = "rayon")]
+pub fn resolve_self_intersections_par<T, R>(poly: &mut Polygon<T>, rng: &mut R) -> Result<(), Error>
+where
+  T: PolygonScalar + Sync,
+  R: Rng + ?Sized,
+{
+  use rayon::prelude::*;
+
+  check_not_colinear(poly)?;
+  let edge_list: Vec<IndexEdge> = edges(poly).collect();
+  let shared: &Polygon<T> = poly;
+  let found: Vec<IndexIntersection> = edge_list
+    .par_iter()
+    .enumerate()
+    .flat_map_iter(|(i, &e1)| {
+      edge_list[i + 1..]
+        .iter()
+        .filter_map(move |&e2| intersects(shared, e1, e2))
+    })
+    .collect();
+  let grid = poly.iter_boundary().len() >= GRID_THRESHOLD;
+  let edge_index = EdgeIndex::new(poly, grid);
+  untangle_all(poly, rng, edge_index, found)
+}
+
+fn check_not_colinear<T: PolygonScalar>(poly: &Polygon<T>) -> Result<(), Error> {
   assert_eq!(poly.rings.len(), 1);
+  // if all points are colinear, return error.
   if poly.iter_boundary().all(|pt| pt.is_colinear()) {
     return Err(Error::InsufficientVertices);
   }
-  // if all points are colinear, return error.
-  // dbg!(&pts);
+  Ok(())
+}
+
+fn untangle_all<T, R>(
+  poly: &mut Polygon<T>,
+  rng: &mut R,
+  mut edge_index: EdgeIndex,
+  found: Vec<IndexIntersection>,
+) -> Result<(), Error>
+where
+  T: PolygonScalar,
+  R: Rng + ?Sized,
+{
   let mut isects = IndexIntersectionSet::new(poly.iter_boundary().len());
-  // dbg!(&poly.rings[0]);
-  for e1 in edges(poly) {
-    for e2 in edges(poly) {
-      if e1 < e2 {
-        if let Some(isect) = intersects(poly, e1, e2) {
-          // eprintln!("Inserting new intersection: {:?} {:?}", e1, e2);
-          isects.push(isect)
-        }
-      }
-    }
+  for isect in found {
+    isects.push(isect)
   }
   // sanity_check(&poly, &isects);
   // dbg!(isects.to_vec());
   while let Some(isect) = isects.random(rng) {
-    untangle(poly, &mut isects, isect)
+    untangle(poly, &mut isects, &mut edge_index, isect)
   }
+  debug_assert!(same_edges(poly, &edge_index.list));
   poly.ensure_ccw()?;
   // poly.validate()?;
   Ok(())
 }
 
+// Current edges of the polygon. With a grid, intersection candidates are the
+// edges in the cells covered by an edge's bounding box instead of every edge.
+struct EdgeIndex {
+  list: Vec<IndexEdge>,
+  grid: Option<EdgeGrid>,
+}
+
+impl EdgeIndex {
+  fn new<T: PolygonScalar>(poly: &Polygon<T>, grid: bool) -> EdgeIndex {
+    let list: Vec<IndexEdge> = edges(poly).collect();
+    let grid = grid.then(|| {
+      let mut grid = EdgeGrid::new(poly);
+      for &edge in &list {
+        grid.insert(edge);
+      }
+      grid
+    });
+    EdgeIndex { list, grid }
+  }
+
+  fn remove(&mut self, removed: &[IndexEdge]) {
+    self.list.retain(|e| !removed.contains(e));
+    if let Some(grid) = &mut self.grid {
+      for &edge in removed {
+        grid.remove(edge);
+      }
+    }
+  }
+
+  fn insert(&mut self, inserted: &[IndexEdge]) {
+    self.list.extend_from_slice(inserted);
+    if let Some(grid) = &mut self.grid {
+      for &edge in inserted {
+        grid.insert(edge);
+      }
+    }
+  }
+
+  // Superset of the edges that may intersect 'edge'.
+  fn candidates(&self, edge: IndexEdge) -> Vec<IndexEdge> {
+    match &self.grid {
+      None => self.list.clone(),
+      Some(grid) => grid.candidates(edge),
+    }
+  }
+}
+
+// Uniform grid over the ranks of the vertex coordinates. Each row and column
+// holds roughly the same number of vertices, and comparing ranks instead of
+// coordinates keeps the cell assignment exact for every scalar type.
+// Vertices never move while untangling so their cells are computed once.
+struct EdgeGrid {
+  width: usize,
+  // Key: PointId
+  cells: Vec<[usize; 2]>,
+  buckets: Vec<Vec<IndexEdge>>,
+}
+
+impl EdgeGrid {
+  fn new<T: PolygonScalar>(poly: &Polygon<T>) -> EdgeGrid {
+    let n = poly.points.len();
+    let mut width = 1;
+    while width * width < n {
+      width += 1;
+    }
+    let mut cells = vec![[0, 0]; n];
+    for axis in 0..2 {
+      let coord = |id: usize| &poly.points[id].array[axis];
+      let mut order: Vec<usize> = (0..n).collect();
+      order.sort_by(|&a, &b| coord(a).total_cmp(coord(b)));
+      // Equal coordinates share the cell of the first of them.
+      let mut first = 0;
+      for (rank, &id) in order.iter().enumerate() {
+        if rank > 0 && coord(order[rank - 1]).total_cmp(coord(id)).is_ne() {
+          first = rank;
+        }
+        cells[id][axis] = first * width / n;
+      }
+    }
+    EdgeGrid {
+      width,
+      cells,
+      buckets: vec![Vec::new(); width * width],
+    }
+  }
+
+  // Buckets covered by the bounding box of 'edge'. Edges whose bounding
+  // boxes overlap share at least one bucket.
+  fn covered(&self, edge: IndexEdge) -> impl Iterator<Item = usize> + '_ {
+    let [ax, ay] = self.cells[edge.min.usize()];
+    let [bx, by] = self.cells[edge.max.usize()];
+    (ax.min(bx)..=ax.max(bx))
+      .flat_map(move |x| (ay.min(by)..=ay.max(by)).map(move |y| x * self.width + y))
+  }
+
+  fn insert(&mut self, edge: IndexEdge) {
+    let covered: Vec<usize> = self.covered(edge).collect();
+    for bucket in covered {
+      self.buckets[bucket].push(edge);
+    }
+  }
+
+  fn remove(&mut self, edge: IndexEdge) {
+    let covered: Vec<usize> = self.covered(edge).collect();
+    for bucket in covered {
+      self.buckets[bucket].retain(|&e| e != edge);
+    }
+  }
+
+  fn candidates(&self, edge: IndexEdge) -> Vec<IndexEdge> {
+    let mut out: Vec<IndexEdge> = self
+      .covered(edge)
+      .flat_map(|bucket| self.buckets[bucket].iter().copied())
+      .collect();
+    out.sort_unstable();
+    out.dedup();
+    out
+  }
+}
+
 // Create list of edges
 // Find all intersections
 /// Generate a valid polygon by connecting a set of points in such a way
@@ -57,22 +265,38 @@ where
 /// # Space complexity
 /// $O(n^2)$
 pub fn two_opt_moves<T, R>(pts: Vec<Point<T>>, rng: &mut R) -> Result<Polygon<T>, Error>
+where
+  T: PolygonScalar,
+  R: Rng + ?Sized,
+{
+  two_opt_moves_from(Polygon::new_unchecked(pts), rng)
+}
+
+/// Same as [`two_opt_moves`] but starts from an existing, possibly
+/// self-intersecting, polygon instead of connecting the points in input
+/// order. Polygons without self-intersections are returned unchanged, except
+/// that clockwise polygons are reversed.
+///
+/// # Time complexity
+/// $O(n^4)$
+/// # Space complexity
+/// $O(n^2)$
+pub fn two_opt_moves_from<T, R>(mut poly: Polygon<T>, rng: &mut R) -> Result<Polygon<T>, Error>
 where
   T: PolygonScalar,
   R: Rng + ?Sized,
 {
   {
     let mut seen = BTreeSet::new();
-    for pt in pts.iter() {
+    for pt in poly.iter() {
       if !seen.insert(pt) {
         return Err(Error::DuplicatePoints);
       }
     }
   }
-  if pts.len() < 3 {
+  if poly.iter().count() < 3 {
     return Err(Error::InsufficientVertices);
   }
-  let mut poly = Polygon::new_unchecked(pts);
   resolve_self_intersections(&mut poly, rng)?;
   Ok(poly)
 }
@@ -133,6 +357,7 @@ where
 fn untangle<T: PolygonScalar>(
   poly: &mut Polygon<T>,
   set: &mut IndexIntersectionSet,
+  edge_index: &mut EdgeIndex,
   isect: IndexIntersection,
 ) {
   // dbg!(vertex_list.vertices().collect::<Vec<Vertex>>());
@@ -212,6 +437,7 @@ fn untangle<T: PolygonScalar>(
     set.remove_all(del_edge_1);
     set.remove_all(del_edge_2);
     set.remove_all(del_edge_3);
+    edge_index.remove(&[del_edge_1, del_edge_2, del_edge_3]);
 
     inserted_edges = vec![
       IndexEdge::new(kink.prev().point_id(), kink.next().point_id()),
@@ -225,8 +451,11 @@ fn untangle<T: PolygonScalar>(
     poly.vertices_join(p1, p2);
   } else {
     // vertex_list.uncross(da, db);
-    set.remove_all(IndexEdge::new(da.point_id(), da.next().point_id()));
-    set.remove_all(IndexEdge::new(db.point_id(), db.next().point_id()));
+    let del_edge_1 = IndexEdge::new(da.point_id(), da.next().point_id());
+    let del_edge_2 = IndexEdge::new(db.point_id(), db.next().point_id());
+    set.remove_all(del_edge_1);
+    set.remove_all(del_edge_2);
+    edge_index.remove(&[del_edge_1, del_edge_2]);
 
     inserted_edges = vec![
       IndexEdge::new(da.point_id(), db.point_id()),
@@ -241,8 +470,9 @@ fn untangle<T: PolygonScalar>(
   }
   // dbg!(&removed_edges, &inserted_edges);
   // eprintln!("New edges: {:?}", &inserted_edges);
+  edge_index.insert(&inserted_edges);
   for &edge in inserted_edges.iter() {
-    for e1 in edges(poly) {
+    for e1 in edge_index.candidates(edge) {
       if e1 != edge {
         if let Some(isect) = intersects(poly, e1, edge) {
           // eprintln!("Inserting new intersection: {:?} {:?}", e1, edge);
@@ -284,6 +514,15 @@ fn naive_intersection_set<T: PolygonScalar>(poly: &Polygon<T>) -> BTreeSet<Index
   set
 }
 
+#[cfg(not(tarpaulin_include))]
+fn same_edges<T: PolygonScalar>(poly: &Polygon<T>, edge_list: &[IndexEdge]) -> bool {
+  let mut cached = edge_list.to_vec();
+  let mut actual: Vec<IndexEdge> = edges(poly).collect();
+  cached.sort();
+  actual.sort();
+  cached == actual
+}
+
 fn parallel_edges<T>(a: Cursor<'_, T>, b: Cursor<'_, T>) -> bool
 where
   T: PolygonScalar,
@@ -292,7 +531,7 @@ where
   let a2 = a.next().point();
   let b1 = b.point();
   let b2 = b.next().point();
-  Point::orient(a1, a2, b1).is_colinear() && Point::orient(a1, a2, b2).is_colinear()
+  Point::collinear(a1, a2, b1) && Point::collinear(a1, a2, b2)
 }
 
 /// Find the leftmost and rightmost vertices that are not linear.
@@ -340,9 +579,8 @@ pub mod tests {
   use rand::SeedableRng;
   use test_strategy::proptest;
 
-  #[test]
-  fn unit_1() {
-    let pts: Vec<Point<i8>> = vec![
+  fn unit_1_points() -> Vec<Point<i8>> {
+    vec![
       Point { array: [-71, 91] },
       Point { array: [-17, -117] },
       Point { array: [-13, 98] },
@@ -414,15 +652,18 @@ pub mod tests {
       Point { array: [-87, -126] },
       Point { array: [-120, -38] },
       Point { array: [90, -111] },
-    ];
-    let ret = two_opt_moves(pts, &mut rand::rngs::SmallRng::seed_from_u64(0));
+    ]
+  }
+
+  #[test]
+  fn unit_1() {
+    let ret = two_opt_moves(unit_1_points(), &mut rand::rngs::SmallRng::seed_from_u64(0));
 
     assert_eq!(ret.and_then(|val| val.validate()).err(), None);
   }
 
-  #[test]
-  fn unit_2() {
-    let pts: Vec<Point<i8>> = vec![
+  fn unit_2_points() -> Vec<Point<i8>> {
+    vec![
       Point { array: [-59, -36] },
       Point { array: [-62, 88] },
       Point { array: [8, 124] },
@@ -511,14 +752,30 @@ pub mod tests {
       Point { array: [26, 27] },
       Point { array: [-69, -44] },
       Point { array: [97, 42] },
-    ];
+    ]
+  }
+
+  #[test]
+  fn unit_2() {
     let mut rng = StepRng::new(0, 0);
     // let mut rng = rand::rngs::SmallRng::seed_from_u64(0);
-    let ret = two_opt_moves(pts, &mut rng);
+    let ret = two_opt_moves(unit_2_points(), &mut rng);
 
     assert_eq!(ret.and_then(|val| val.validate()).err(), None);
   }
 
+  #[test]
+  fn fixtures_naive_and_grid() {
+    for pts in [unit_1_points(), unit_2_points()] {
+      for grid in [false, true] {
+        let mut poly = Polygon::new_unchecked(pts.clone());
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(0);
+        resolve_with(&mut poly, &mut rng, grid).unwrap();
+        assert_eq!(poly.validate().err(), None);
+      }
+    }
+  }
+
   #[test]
   fn unit_3() {
     let pts: Vec<Point<i8>> = vec![
@@ -535,6 +792,53 @@ pub mod tests {
     assert_eq!(ret.and_then(|val| val.validate()).err(), None);
   }
 
+  #[test]
+  fn many_points() {
+    let mut rng = rand::rngs::SmallRng::seed_from_u64(0);
+    let pts: BTreeSet<Point<i32>> = (0..200)
+      .map(|_| Point::new([rng.gen_range(-1000..1000), rng.gen_range(-1000..1000)]))
+      .collect();
+    let mut pts: Vec<Point<i32>> = pts.into_iter().collect();
+    pts.shuffle(&mut rng);
+    let ret = two_opt_moves(pts, &mut rng);
+    assert_eq!(ret.and_then(|val| val.validate()).err(), None);
+  }
+
+  #[cfg(feature = "rayon")]
+  #[proptest]
+  fn parallel_scan(
+    #[strategy(vec(any::<Point<i8>>(), 3..100))] mut pts: Vec<Point<i8>>,
+    seed: u64,
+  ) {
+    let mut set = BTreeSet::new();
+    pts.retain(|pt| set.insert(*pt));
+    if pts.len() >= 3 && !Point::all_colinear(&pts) {
+      let mut seq = Polygon::new_unchecked(pts.clone());
+      let mut par = Polygon::new_unchecked(pts);
+      let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+      resolve_self_intersections(&mut seq, &mut rng).unwrap();
+      let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+      resolve_self_intersections_par(&mut par, &mut rng).unwrap();
+      prop_assert_eq!(seq.validate().err(), None);
+      prop_assert_eq!(par.validate().err(), None);
+    }
+  }
+
+  #[proptest]
+  fn from_simple_polygon_is_noop(
+    #[strategy(vec(any::<Point<i8>>(), 3..100))] mut pts: Vec<Point<i8>>,
+  ) {
+    let mut set = BTreeSet::new();
+    pts.retain(|pt| set.insert(*pt));
+    if pts.len() >= 3 && !Point::all_colinear(&pts) {
+      let mut rng = StepRng::new(0, 0);
+      let simple = two_opt_moves(pts, &mut rng).unwrap();
+      let again = two_opt_moves_from(simple.clone(), &mut rng).unwrap();
+      prop_assert_eq!(again.points, simple.points);
+      prop_assert_eq!(again.rings, simple.rings);
+    }
+  }
+
   #[proptest]
   fn points_to_polygon(#[strategy(vec(any::<Point<i8>>(), 3..100))] mut pts: Vec<Point<i8>>) {
     let mut set = BTreeSet::new();
@@ -546,6 +850,31 @@ pub mod tests {
     }
   }
 
+  #[proptest]
+  fn grid_candidates(#[strategy(vec(any::<Point<i8>>(), 3..100))] mut pts: Vec<Point<i8>>) {
+    // Zero length edges from duplicate points report spurious intersections.
+    let mut set = BTreeSet::new();
+    pts.retain(|pt| set.insert(*pt));
+    prop_assume!(pts.len() >= 3);
+    let poly = Polygon::new_unchecked(pts);
+    let edge_index = EdgeIndex::new(&poly, true);
+    for isect in naive_intersection_set(&poly) {
+      let (e1, e2) = (isect.min, isect.max);
+      prop_assert!(edge_index.candidates(e1).contains(&e2));
+    }
+  }
+
+  #[proptest]
+  fn grid_to_polygon(#[strategy(vec(any::<Point<i8>>(), 3..100))] mut pts: Vec<Point<i8>>) {
+    let mut set = BTreeSet::new();
+    pts.retain(|pt| set.insert(*pt));
+    if pts.len() >= 3 && !Point::all_colinear(&pts) {
+      let mut poly = Polygon::new_unchecked(pts);
+      resolve_with(&mut poly, &mut StepRng::new(0, 0), true).unwrap();
+      prop_assert_eq!(poly.validate().err(), None);
+    }
+  }
+
   #[proptest]
   fn f64_to_polygon(#[strategy(vec(any_nn(), 3..100))] mut pts: Vec<Point<NotNan<f64>>>) {
     let mut set = BTreeSet::new();
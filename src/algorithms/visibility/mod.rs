@@ -1 +1,3 @@
 pub mod naive;
+
+pub use naive::visibility_with_holes;
@@ -1,7 +1,8 @@
 use std::cmp::Ordering;
 
 use crate::data::{
-  Cursor, DirectedEdge, Direction, HalfLineSoS, IHalfLineLineSegmentSoS, Line, Point, Polygon,
+  DirectedEdge, Direction, HalfLineSoS, IHalfLineLineSegmentSoS, Line, Point, PointLocation,
+  Polygon,
 };
 use crate::{Intersects, Orientation, PolygonScalar};
 
@@ -94,17 +95,96 @@ where
   T: PolygonScalar,
 {
   // FIXME: We want to iterate over all vertices, not just boundary vertices.
-  let mut vertices: Vec<Cursor<'_, T>> = polygon.iter_boundary().collect();
+  let mut vertices: Vec<&Point<T>> = polygon
+    .iter_boundary()
+    .map(|cursor| cursor.point())
+    .collect();
   vertices.sort_by(|a, b| point.ccw_cmp_around(a, b));
+  // FIXME: We want to iterate over all edges, not just boundary edges.
+  let edges: Vec<DirectedEdge<'_, T>> = polygon.iter_boundary_edges().collect();
+  visibility_polygon(point, vertices, &edges)
+}
+
+/// Visibility polygon of a point inside `outer` but outside each of `holes`.
+/// The holes are treated as obstacles and may be given in either
+/// orientation. Returns `None` if the point is outside `outer` or strictly
+/// inside one of the holes.
+///
+/// # Time complexity
+///
+/// $O(n^2)$ where $n$ is the total number of vertices.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// # use rgeometry::algorithms::visibility::visibility_with_holes;
+/// # fn main() -> Result<(), rgeometry::Error> {
+/// let room = Polygon::new(vec![
+///   Point::new([0, 0]),
+///   Point::new([10, 0]),
+///   Point::new([10, 10]),
+///   Point::new([0, 10]),
+/// ])?;
+/// let pillar = Polygon::new(vec![
+///   Point::new([4, 4]),
+///   Point::new([6, 4]),
+///   Point::new([6, 6]),
+///   Point::new([4, 6]),
+/// ])?;
+/// let visible = visibility_with_holes(&Point::new([1, 5]), &room, &[pillar]).unwrap();
+/// assert_eq!(visible.iter().count(), 8);
+/// # Ok(())
+/// # }
+/// ```
+pub fn visibility_with_holes<T>(
+  viewpoint: &Point<T>,
+  outer: &Polygon<T>,
+  holes: &[Polygon<T>],
+) -> Option<Polygon<T>>
+where
+  T: PolygonScalar,
+{
+  if outer.locate(viewpoint) == PointLocation::Outside
+    || holes
+      .iter()
+      .any(|hole| hole.locate(viewpoint) == PointLocation::Inside)
+  {
+    return None;
+  }
+  let rings = std::iter::once(outer).chain(holes);
+  let mut vertices: Vec<&Point<T>> = rings
+    .clone()
+    .flat_map(|ring| ring.iter_boundary().map(|cursor| cursor.point()))
+    .collect();
+  // The rings aren't one boundary, so visit the vertices in angular order
+  // instead. Rays towards vertices at the same angle hit the same edges, so
+  // only the nearest vertex at each angle is needed.
+  vertices.sort_by(|a, b| {
+    viewpoint
+      .ccw_cmp_around(a, b)
+      .then_with(|| viewpoint.cmp_distance_to(a, b))
+  });
+  vertices.dedup_by(|a, b| viewpoint.ccw_cmp_around(a, b) == Ordering::Equal);
+  let edges: Vec<DirectedEdge<'_, T>> = rings.flat_map(Polygon::iter_boundary_edges).collect();
+  visibility_polygon(viewpoint, vertices, &edges)
+}
 
+fn visibility_polygon<T>(
+  point: &Point<T>,
+  vertices: Vec<&Point<T>>,
+  edges: &[DirectedEdge<'_, T>],
+) -> Option<Polygon<T>>
+where
+  T: PolygonScalar,
+{
   let mut polygon_points = Vec::new();
   for vertex in vertices {
-    let ray_sos = HalfLineSoS::new_through(point, vertex.point());
+    let ray_sos = HalfLineSoS::new_through(point, vertex);
     let mut right_intersection = NearestIntersection::new(point);
     let mut left_intersection = NearestIntersection::new(point);
 
-    // FIXME: We want to iterate over all edges, not just boundary edges.
-    for edge in polygon.iter_boundary_edges() {
+    for &edge in edges {
       use IHalfLineLineSegmentSoS::*;
       use Orientation::*;
       match ray_sos.intersect(edge) {
@@ -128,7 +208,7 @@ where
 
     match right_intersection.take() {
       Some(interesection) => {
-        if point.cmp_distance_to(&interesection, &vertex) != Ordering::Less {
+        if point.cmp_distance_to(&interesection, vertex) != Ordering::Less {
           polygon_points.push(interesection);
         }
       }
@@ -136,7 +216,7 @@ where
     };
     match left_intersection.take() {
       Some(intersection) => {
-        if point.cmp_distance_to(&intersection, &vertex) != Ordering::Less {
+        if point.cmp_distance_to(&intersection, vertex) != Ordering::Less {
           polygon_points.push(intersection);
         }
       }
@@ -305,6 +385,69 @@ mod naive_testing {
     }
   }
 
+  //   Input            Output
+  //  /---------\     /-------\
+  //  |   /-\   |     |   /-\_/
+  //  | x | |   |     | x | |
+  //  |   \-/   |     |   \-/-\
+  //  \---------/     \-------/
+  #[test]
+  fn pillar_shadow() {
+    let point = Point::new([1, 5]);
+    let room = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([10, 0]),
+      Point::new([10, 10]),
+      Point::new([0, 10]),
+    ])
+    .unwrap();
+    let pillar = Polygon::new(vec![
+      Point::new([4, 4]),
+      Point::new([6, 4]),
+      Point::new([6, 6]),
+      Point::new([4, 6]),
+    ])
+    .unwrap();
+    let out_polygon = visibility_with_holes(&point, &room, &[pillar]).unwrap();
+    // The pillar hides the wall between (10,2) and (10,8).
+    let expected = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([10, 0]),
+      Point::new([10, 2]),
+      Point::new([4, 4]),
+      Point::new([4, 6]),
+      Point::new([10, 8]),
+      Point::new([10, 10]),
+      Point::new([0, 10]),
+    ])
+    .unwrap();
+    assert!(out_polygon.equals(&expected));
+  }
+
+  #[test]
+  fn viewpoint_not_free() {
+    let room = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([10, 0]),
+      Point::new([10, 10]),
+      Point::new([0, 10]),
+    ])
+    .unwrap();
+    let pillar = Polygon::new(vec![
+      Point::new([4, 4]),
+      Point::new([6, 4]),
+      Point::new([6, 6]),
+      Point::new([4, 6]),
+    ])
+    .unwrap();
+    let holes = [pillar];
+    // Inside the pillar.
+    assert!(visibility_with_holes(&Point::new([5, 5]), &room, &holes).is_none());
+    // Outside the room, facing it.
+    assert!(visibility_with_holes(&Point::new([-5, 5]), &room, &holes).is_none());
+    assert!(visibility_with_holes(&Point::new([15, 12]), &room, &holes).is_none());
+  }
+
   // test with rotating square
   #[test]
   fn test_rotating_square() {
@@ -1,26 +1,32 @@
+mod circle;
 mod directed_edge;
 mod intersection_set;
+mod kd_tree;
 mod line;
 mod line_segment;
 pub(crate) mod point;
 pub mod polygon;
+mod range_tree;
 mod triangle;
 mod vector;
 
+pub use circle::*;
 pub use directed_edge::*;
 pub use intersection_set::*;
+pub use kd_tree::KdTree;
 pub use line::*;
 pub use line_segment::*;
+pub use range_tree::RangeTree;
 pub use triangle::*;
 
 // pub use crate::polygon::EdgeIter as testing;
 
 #[doc(inline)]
 pub use crate::data::polygon::{
-  Cursor, DirectedIndexEdge, IndexEdge, PointId, Polygon, PolygonConvex, Position, PositionId,
-  RingId,
+  Calipers, Cursor, DirectedIndexEdge, IndexEdge, PointId, Polygon, PolygonConvex, Position,
+  PositionId, RingId,
 };
-pub use crate::transformation::Transform;
+pub use crate::transformation::{Transform, TransformBuilder};
 pub use point::Point;
 pub use vector::{Vector, VectorView};
 
@@ -0,0 +1,183 @@
+use super::{LineSegmentView, Point};
+use crate::{Orientation, PolygonScalar};
+
+/// Circle given by its center and squared radius. Keeping the radius squared
+/// lets integer circles answer containment queries exactly.
+#[derive(Debug, Clone)]
+pub struct Circle<T> {
+  pub center: Point<T, 2>,
+  pub radius_squared: T,
+}
+
+impl<T> Circle<T>
+where
+  T: PolygonScalar,
+{
+  /// Circle around `center` whose radius is the square root of
+  /// `radius_squared`.
+  pub fn new(center: Point<T, 2>, radius_squared: T) -> Circle<T> {
+    Circle {
+      center,
+      radius_squared,
+    }
+  }
+
+  /// Circle through three points, or `None` if they are colinear.
+  ///
+  /// The center is computed with the circumcircle formula, which divides in
+  /// `T`. Integer coordinates are therefore truncated and should be converted
+  /// to a rational or floating point type first.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let (a, b, c) = (Point::new([0.0, 0.0]), Point::new([4.0, 0.0]), Point::new([0.0, 2.0]));
+  /// let circle = Circle::from_three_points(&a, &b, &c).unwrap();
+  /// assert_eq!(circle.center, Point::new([2.0, 1.0]));
+  /// assert_eq!(circle.radius_squared, 5.0);
+  /// ```
+  pub fn from_three_points(a: &Point<T, 2>, b: &Point<T, 2>, c: &Point<T, 2>) -> Option<Circle<T>> {
+    if Point::orient(a, b, c) == Orientation::CoLinear {
+      return None;
+    }
+    let [ax, ay] = a.array.clone();
+    let [bx, by] = b.array.clone();
+    let [cx, cy] = c.array.clone();
+    let a2 = ax.clone() * ax.clone() + ay.clone() * ay.clone();
+    let b2 = bx.clone() * bx.clone() + by.clone() * by.clone();
+    let c2 = cx.clone() * cx.clone() + cy.clone() * cy.clone();
+    let d = T::from_constant(2)
+      * (ax.clone() * (by.clone() - cy.clone())
+        + bx.clone() * (cy.clone() - ay.clone())
+        + cx.clone() * (ay.clone() - by.clone()));
+    let ux = (a2.clone() * (by.clone() - cy.clone())
+      + b2.clone() * (cy - ay.clone())
+      + c2.clone() * (ay - by))
+      / d.clone();
+    let uy = (a2 * (cx.clone() - bx.clone()) + b2 * (ax.clone() - cx) + c2 * (bx - ax)) / d;
+    let center = Point::new([ux, uy]);
+    let radius_squared = a.squared_euclidean_distance(&center);
+    Some(Circle {
+      center,
+      radius_squared,
+    })
+  }
+
+  /// True if `p` is inside the circle or on its boundary.
+  ///
+  /// # Panics
+  ///
+  /// May panic for bounded types (i8, isize, etc) if the squared distance
+  /// overflows.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let circle = Circle::new(Point::new([0, 0]), 25);
+  /// assert!(circle.contains(&Point::new([3, 4])));
+  /// assert!(!circle.contains(&Point::new([4, 4])));
+  /// ```
+  pub fn contains(&self, p: &Point<T, 2>) -> bool {
+    p.squared_euclidean_distance::<T>(&self.center) <= self.radius_squared
+  }
+
+  /// True if any point of `seg` is inside the circle or on its boundary.
+  /// Both endpoints of the segment are treated as inclusive.
+  ///
+  /// # Panics
+  ///
+  /// May panic for bounded types (i8, isize, etc) if intermediate products
+  /// overflow.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let circle = Circle::new(Point::new([0, 0]), 4);
+  /// let (a, b, c) = (Point::new([-5, 1]), Point::new([5, 1]), Point::new([5, 5]));
+  /// assert!(circle.intersects_segment(&LineSegmentView::from(&a..&b)));
+  /// assert!(!circle.intersects_segment(&LineSegmentView::from(&a..&c)));
+  /// ```
+  pub fn intersects_segment(&self, seg: &LineSegmentView<'_, T, 2>) -> bool {
+    let a = seg.min.inner();
+    let b = seg.max.inner();
+    let sub = |p: &Point<T, 2>, q: &Point<T, 2>| {
+      [
+        p.array[0].clone() - q.array[0].clone(),
+        p.array[1].clone() - q.array[1].clone(),
+      ]
+    };
+    let dot = |u: &[T; 2], v: &[T; 2]| u[0].clone() * v[0].clone() + u[1].clone() * v[1].clone();
+    let edge = sub(b, a);
+    let to_center = sub(&self.center, a);
+    let along = dot(&to_center, &edge);
+    let length_squared = dot(&edge, &edge);
+    if along <= T::from_constant(0) {
+      return self.contains(a);
+    }
+    if along >= length_squared {
+      return self.contains(b);
+    }
+    // The closest point is between the endpoints. Its squared distance to
+    // the center is cross^2 / |edge|^2.
+    let cross = to_center[0].clone() * edge[1].clone() - to_center[1].clone() * edge[0].clone();
+    cross.clone() * cross <= self.radius_squared.clone() * length_squared
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn contains_cases() {
+    let circle = Circle::new(Point::new([1, 1]), 25);
+    // Inside.
+    assert!(circle.contains(&Point::new([1, 1])));
+    assert!(circle.contains(&Point::new([3, 3])));
+    // On the boundary.
+    assert!(circle.contains(&Point::new([4, 5])));
+    assert!(circle.contains(&Point::new([1, -4])));
+    // Outside.
+    assert!(!circle.contains(&Point::new([5, 5])));
+    assert!(!circle.contains(&Point::new([1, 7])));
+  }
+
+  #[test]
+  fn segment_cases() {
+    let circle = Circle::new(Point::new([0, 0]), 25);
+    let hits = |a: [i32; 2], b: [i32; 2]| {
+      let (a, b) = (Point::new(a), Point::new(b));
+      circle.intersects_segment(&LineSegmentView::from(&a..&b))
+    };
+    // Pierces the circle without an endpoint inside.
+    assert!(hits([-10, 2], [10, 2]));
+    // Tangent at (0, 5).
+    assert!(hits([-10, 5], [10, 5]));
+    // Misses above.
+    assert!(!hits([-10, 6], [10, 6]));
+    // The line pierces the circle but the segment stops short.
+    assert!(!hits([6, 0], [10, 0]));
+    // Entirely inside.
+    assert!(hits([-1, 0], [1, 0]));
+    // Degenerate segment on the boundary.
+    assert!(hits([3, 4], [3, 4]));
+  }
+
+  #[test]
+  fn from_three_points_cases() {
+    let (a, b, c) = (
+      Point::new([0.0, 0.0]),
+      Point::new([4.0, 0.0]),
+      Point::new([0.0, 4.0]),
+    );
+    let circle = Circle::from_three_points(&a, &b, &c).unwrap();
+    assert_eq!(circle.center, Point::new([2.0, 2.0]));
+    assert_eq!(circle.radius_squared, 8.0);
+    assert!(circle.contains(&Point::new([4.0, 4.0])));
+    let d = Point::new([8.0, 0.0]);
+    assert!(Circle::from_three_points(&a, &b, &d).is_none());
+  }
+}
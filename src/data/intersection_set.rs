@@ -1,7 +1,9 @@
 use crate::data::IndexEdge;
 use crate::data::PointId;
+use crate::data::{EndPoint, ILineSegmentExact, LineSegmentView, Point, Polygon};
 use crate::utils::SparseIndex;
 use crate::utils::SparseVec;
+use crate::{PolygonScalar, ToRational};
 
 use rand::Rng;
 use std::ops::{Index, IndexMut};
@@ -111,6 +113,36 @@ impl IndexIntersectionSet {
   pub fn iter(&self) -> impl Iterator<Item = IndexIntersection> + '_ {
     self.by_idx.iter().map(|&isect| isect.into())
   }
+
+  /// Intersecting edges of `poly` together with the point where they meet.
+  /// Crossings are computed exactly and then rounded. Overlapping edges are
+  /// reported with the midpoint of their overlap. Pairs that no longer
+  /// intersect are skipped.
+  pub fn resolved_points<'a, T>(
+    &'a self,
+    poly: &'a Polygon<T>,
+  ) -> impl Iterator<Item = (IndexEdge, IndexEdge, Point<f64>)> + 'a
+  where
+    T: PolygonScalar + ToRational,
+  {
+    let segment = |edge: IndexEdge| {
+      LineSegmentView::new(
+        EndPoint::Inclusive(poly.point(edge.min)),
+        EndPoint::Inclusive(poly.point(edge.max)),
+      )
+    };
+    self.iter().filter_map(move |isect| {
+      let pt = match segment(isect.min).intersection_point_exact(segment(isect.max))? {
+        ILineSegmentExact::Crossing(pt) => Point::from(pt),
+        ILineSegmentExact::Overlap(overlap) => {
+          let [x1, y1] = Point::<f64>::from(overlap.min.inner()).array;
+          let [x2, y2] = Point::<f64>::from(overlap.max.inner()).array;
+          Point::new([(x1 + x2) / 2.0, (y1 + y2) / 2.0])
+        }
+      };
+      Some((isect.min, isect.max, pt))
+    })
+  }
 }
 
 impl IndexMut<IndexEdge> for IndexIntersectionSet {
@@ -252,3 +284,27 @@ impl From<IsectEdge> for IndexEdge {
     IndexEdge::new(e.vertex0, e.vertex1)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn resolved_points_bowtie() {
+    let poly = Polygon::new_unchecked(vec![
+      Point::new([0, 0]),
+      Point::new([2, 2]),
+      Point::new([2, 0]),
+      Point::new([0, 2]),
+    ]);
+    let diagonal_a = IndexEdge::new(PointId(0), PointId(1));
+    let diagonal_b = IndexEdge::new(PointId(2), PointId(3));
+    let mut set = IndexIntersectionSet::new(4);
+    set.push(IndexIntersection::new(diagonal_a, diagonal_b));
+    let points: Vec<_> = set.resolved_points(&poly).collect();
+    assert_eq!(
+      points,
+      vec![(diagonal_a, diagonal_b, Point::new([1.0, 1.0]))]
+    );
+  }
+}
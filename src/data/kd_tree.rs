@@ -0,0 +1,187 @@
+use std::cmp::Ordering;
+
+use super::{Point, PointId};
+use crate::PolygonScalar;
+
+/// Static 2D tree for nearest-neighbor and range queries.
+///
+/// The points are stored in an implicit balanced tree: the median of each
+/// slice (along x on even depths, y on odd depths) is the root of that slice.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// let tree = KdTree::new(vec![
+///   Point::new([0, 0]),
+///   Point::new([5, 5]),
+///   Point::new([9, 1]),
+/// ]);
+/// assert_eq!(tree.point(tree.nearest(&Point::new([7, 2]))), &Point::new([9, 1]));
+/// assert_eq!(tree.within_radius_squared(&Point::new([1, 1]), &2).len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KdTree<T> {
+  points: Vec<Point<T>>,
+  // Indices into 'points' arranged as an implicit tree.
+  order: Vec<usize>,
+}
+
+impl<T: PolygonScalar> KdTree<T> {
+  /// # Time complexity
+  ///
+  /// $O(n \log n)$
+  pub fn new(points: Vec<Point<T>>) -> KdTree<T> {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    build(&points, &mut order, 0);
+    KdTree { points, order }
+  }
+
+  pub fn point(&self, id: PointId) -> &Point<T> {
+    &self.points[id.0]
+  }
+
+  pub fn len(&self) -> usize {
+    self.points.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.points.is_empty()
+  }
+
+  /// Closest point to `p`. Distances are compared exactly.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the tree is empty.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(\log n)$ expected for well-distributed points, $O(n)$ worst case.
+  pub fn nearest(&self, p: &Point<T>) -> PointId {
+    assert!(!self.is_empty(), "nearest neighbor of an empty tree");
+    let mut best = self.order[self.order.len() / 2];
+    self.nearest_in(&self.order, 0, p, &mut best);
+    PointId(best)
+  }
+
+  fn nearest_in(&self, order: &[usize], depth: usize, p: &Point<T>, best: &mut usize) {
+    if order.is_empty() {
+      return;
+    }
+    let mid = order.len() / 2;
+    let q = &self.points[order[mid]];
+    if p.cmp_distance_to(q, &self.points[*best]) == Ordering::Less {
+      *best = order[mid];
+    }
+    let axis = depth % 2;
+    let (near, far) = if p.array[axis] < q.array[axis] {
+      (&order[..mid], &order[mid + 1..])
+    } else {
+      (&order[mid + 1..], &order[..mid])
+    };
+    self.nearest_in(near, depth + 1, p, best);
+    // Closest possible point on the other side of the splitting line.
+    let mut projection = p.clone();
+    projection.array[axis] = q.array[axis].clone();
+    if p.cmp_distance_to(&projection, &self.points[*best]) != Ordering::Greater {
+      self.nearest_in(far, depth + 1, p, best);
+    }
+  }
+
+  /// All points with a squared distance to `p` of at most `r2`, ordered by
+  /// their id.
+  ///
+  /// # Panics
+  ///
+  /// May panic for bounded types (i8, isize, etc) if squared distances
+  /// overflow.
+  pub fn within_radius_squared(&self, p: &Point<T>, r2: &T) -> Vec<PointId> {
+    let mut found = Vec::new();
+    self.within_in(&self.order, 0, p, r2, &mut found);
+    found.sort_unstable();
+    found.into_iter().map(PointId).collect()
+  }
+
+  fn within_in(&self, order: &[usize], depth: usize, p: &Point<T>, r2: &T, found: &mut Vec<usize>) {
+    if order.is_empty() {
+      return;
+    }
+    let mid = order.len() / 2;
+    let q = &self.points[order[mid]];
+    if &p.squared_euclidean_distance::<T>(q) <= r2 {
+      found.push(order[mid]);
+    }
+    let axis = depth % 2;
+    let delta = p.array[axis].clone() - q.array[axis].clone();
+    let plane_distance = delta.clone() * delta;
+    if p.array[axis] <= q.array[axis] || &plane_distance <= r2 {
+      self.within_in(&order[..mid], depth + 1, p, r2, found);
+    }
+    if p.array[axis] >= q.array[axis] || &plane_distance <= r2 {
+      self.within_in(&order[mid + 1..], depth + 1, p, r2, found);
+    }
+  }
+}
+
+// Points left of the median are not greater along the axis and points right
+// of the median are not smaller.
+fn build<T: PolygonScalar>(points: &[Point<T>], order: &mut [usize], depth: usize) {
+  if order.len() <= 1 {
+    return;
+  }
+  let axis = depth % 2;
+  let mid = order.len() / 2;
+  order.select_nth_unstable_by(mid, |&a, &b| {
+    points[a].array[axis].total_cmp(&points[b].array[axis])
+  });
+  let (left, right) = order.split_at_mut(mid);
+  build(points, left, depth + 1);
+  build(points, &mut right[1..], depth + 1);
+}
+
+#[cfg(test)]
+#[cfg(not(tarpaulin_include))]
+mod tests {
+  use super::*;
+
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  #[test]
+  fn nearest_grid() {
+    let pts: Vec<Point<i32>> = (0..10)
+      .flat_map(|x| (0..10).map(move |y| Point::new([x * 10, y * 10])))
+      .collect();
+    let tree = KdTree::new(pts);
+    let id = tree.nearest(&Point::new([42, 67]));
+    assert_eq!(tree.point(id), &Point::new([40, 70]));
+    let found = tree.within_radius_squared(&Point::new([50, 50]), &100);
+    assert_eq!(found.len(), 5);
+  }
+
+  #[proptest]
+  fn nearest_prop(#[strategy(vec(any::<Point<i8>>(), 1..50))] pts: Vec<Point<i8>>, p: Point<i8>) {
+    let tree = KdTree::new(pts.clone());
+    let nearest = tree.point(tree.nearest(&p));
+    let brute = pts.iter().min_by(|a, b| p.cmp_distance_to(a, b)).unwrap();
+    prop_assert_eq!(p.cmp_distance_to(nearest, brute), Ordering::Equal);
+  }
+
+  #[proptest]
+  fn within_radius_squared_prop(
+    #[strategy(vec(any::<Point<i8>>(), 0..50))] pts: Vec<Point<i8>>,
+    p: Point<i8>,
+    #[strategy(0..20000)] r2: i32,
+  ) {
+    let pts: Vec<Point<i32>> = pts.iter().map(Point::cast).collect();
+    let p: Point<i32> = p.cast();
+    let tree = KdTree::new(pts.clone());
+    let brute: Vec<PointId> = (0..pts.len())
+      .filter(|&i| p.squared_euclidean_distance::<i32>(&pts[i]) <= r2)
+      .map(PointId)
+      .collect();
+    prop_assert_eq!(tree.within_radius_squared(&p, &r2), brute);
+  }
+}
@@ -3,7 +3,9 @@ use super::LineSegmentView;
 use super::Point;
 use super::Vector;
 use crate::Intersects;
-use crate::{Orientation, PolygonScalar};
+use crate::{Orientation, PolygonScalar, TotalOrd};
+
+use num_traits::ToPrimitive;
 
 ///////////////////////////////////////////////////////////////////////////////
 // Line
@@ -60,6 +62,104 @@ impl<T: PolygonScalar> Line<'_, T> {
   }
 }
 
+impl<T: PolygonScalar + ToPrimitive> Line<'_, T> {
+  /// Signed distance from the line to `p`.
+  ///
+  /// The distance is positive when `p` lies to the left of the line (a
+  /// counter-clockwise turn) and negative when it lies to the right.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the line has no direction.
+  pub fn distance_to_point(&self, p: &Point<T, 2>) -> f64 {
+    let (origin, direction) = self.float_parts();
+    let [dx, dy] = direction.0;
+    let [px, py] = (p.to_f64() - origin).0;
+    (dx * py - dy * px) / direction.squared_magnitude().sqrt()
+  }
+
+  /// Closest point on the line to `p`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the line has no direction.
+  pub fn project_point(&self, p: &Point<T, 2>) -> Point<f64, 2> {
+    let (origin, direction) = self.float_parts();
+    let [dx, dy] = direction.0;
+    let [px, py] = (p.to_f64() - origin).0;
+    let t = (dx * px + dy * py) / direction.squared_magnitude();
+    origin + direction * t
+  }
+
+  fn float_parts(&self) -> (Point<f64, 2>, Vector<f64, 2>) {
+    let origin = self.origin.to_f64();
+    let direction = match self.direction {
+      Direction::Vector(v) => v.map(|c| c.to_f64().unwrap()),
+      Direction::Through(pt) => pt.to_f64() - origin,
+    };
+    assert!(
+      direction.squared_magnitude() != 0.0,
+      "line direction must be non-zero"
+    );
+    (origin, direction)
+  }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Line intersection
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ILineLine<T: TotalOrd> {
+  Crossing(Point<T>), // Lines meet at a single point.
+  Coincident,         // Lines are identical.
+}
+
+impl<T: PolygonScalar> Line<'_, T> {
+  fn direction_array(&self) -> [T; 2] {
+    match self.direction {
+      Direction::Vector(v) => v.0.clone(),
+      Direction::Through(pt) => [
+        pt.array[0].clone() - self.origin.array[0].clone(),
+        pt.array[1].clone() - self.origin.array[1].clone(),
+      ],
+    }
+  }
+}
+
+// Full line / line intersection. Parallel lines are detected exactly. The
+// crossing point is computed with the arithmetic of 'T': it is exact for
+// rational types, rounded for floating point types and the division is
+// truncated toward zero for integer types.
+//
+// May panic for bounded types (i8, isize, etc) if the arithmetic overflows.
+impl<'a, T> Intersects for Line<'a, T>
+where
+  T: PolygonScalar,
+{
+  type Result = ILineLine<T>;
+  fn intersect(self, other: Line<'a, T>) -> Option<Self::Result> {
+    let cross = |a: &[T; 2], b: &[T; 2]| a[0].clone() * b[1].clone() - a[1].clone() * b[0].clone();
+    let d1 = self.direction_array();
+    let d2 = other.direction_array();
+    let [px, py] = self.origin.array.clone();
+    let offset = [
+      other.origin.array[0].clone() - px.clone(),
+      other.origin.array[1].clone() - py.clone(),
+    ];
+    let zero = T::from_constant(0);
+    let denom = cross(&d1, &d2);
+    if denom == zero {
+      return (cross(&d1, &offset) == zero).then_some(ILineLine::Coincident);
+    }
+    let numer = cross(&offset, &d2);
+    let [dx, dy] = d1;
+    Some(ILineLine::Crossing(Point::new([
+      px + dx * numer.clone() / denom.clone(),
+      py + dy * numer / denom,
+    ])))
+  }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Line (owned)
 
@@ -78,6 +178,18 @@ impl<'a, T, const N: usize> From<&'a Line_<T, N>> for Line<'a, T, N> {
   }
 }
 
+impl<T: PolygonScalar + ToPrimitive> Line_<T, 2> {
+  /// See [`Line::distance_to_point`].
+  pub fn distance_to_point(&self, p: &Point<T, 2>) -> f64 {
+    Line::from(self).distance_to_point(p)
+  }
+
+  /// See [`Line::project_point`].
+  pub fn project_point(&self, p: &Point<T, 2>) -> Point<f64, 2> {
+    Line::from(self).project_point(p)
+  }
+}
+
 // impl<'a, T: PolygonScalar> Line<'a, T, 2> {
 //   pub fn intersection_point(&self, other: &Self) -> Option<Point<T, 2>> {
 
@@ -363,6 +475,63 @@ mod tests {
   use proptest::prelude::*;
   use test_strategy::proptest;
 
+  #[test]
+  fn line_line_perpendicular() {
+    let (a, b) = (Point::new([0, 3]), Point::new([1, 3]));
+    let (c, d) = (Point::new([2, 0]), Vector([0, 5]));
+    let l1 = Line::new_through(&a, &b);
+    let l2 = Line::new_directed(&c, &d);
+    assert_eq!(
+      l1.intersect(l2),
+      Some(ILineLine::Crossing(Point::new([2, 3])))
+    );
+    assert_eq!(
+      l2.intersect(l1),
+      Some(ILineLine::Crossing(Point::new([2, 3])))
+    );
+  }
+
+  #[test]
+  fn line_line_exact() {
+    let pt = |x: i32, y: i32| Point::new([x, y]).map(|v| num::BigRational::from_integer(v.into()));
+    let (a, b) = (pt(0, 0), pt(1, 1));
+    let (c, d) = (pt(0, 1), pt(2, 0));
+    let third = num::BigRational::new(2.into(), 3.into());
+    assert_eq!(
+      Line::new_through(&a, &b).intersect(Line::new_through(&c, &d)),
+      Some(ILineLine::Crossing(Point::new([third.clone(), third])))
+    );
+    let (a, b): (Point<f64>, Point<f64>) = (Point::new([0.0, 0.0]), Point::new([1.0, 1.0]));
+    let (c, d) = (Point::new([0.0, 1.0]), Point::new([2.0, 0.0]));
+    let Some(ILineLine::Crossing(isect)) =
+      Line::new_through(&a, &b).intersect(Line::new_through(&c, &d))
+    else {
+      panic!("lines cross");
+    };
+    assert!((isect.array[0] - 2.0 / 3.0).abs() < 1e-12);
+    assert!((isect.array[1] - 2.0 / 3.0).abs() < 1e-12);
+  }
+
+  #[test]
+  fn line_line_parallel() {
+    let (a, b) = (Point::new([0, 0]), Point::new([2, 1]));
+    let (c, d) = (Point::new([0, 1]), Vector([4, 2]));
+    let l1 = Line::new_through(&a, &b);
+    assert_eq!(l1.intersect(Line::new_directed(&c, &d)), None);
+  }
+
+  #[test]
+  fn line_line_coincident() {
+    let (a, b) = (Point::new([0, 0]), Point::new([2, 1]));
+    let (c, d) = (Point::new([4, 2]), Vector([-6, -3]));
+    let l1 = Line::new_through(&a, &b);
+    assert_eq!(
+      l1.intersect(Line::new_directed(&c, &d)),
+      Some(ILineLine::Coincident)
+    );
+    assert_eq!(l1.intersect(l1), Some(ILineLine::Coincident));
+  }
+
   #[test]
   fn ray_intersect_unit_1() {
     let line: LineSegment<i8> = LineSegment::from((-1, 127)..(-5, 48));
@@ -437,6 +606,61 @@ mod tests {
     assert!(ray.intersect(line.as_ref()).is_none());
   }
 
+  #[test]
+  fn distance_on_line() {
+    let origin = Point::new([1, 1]);
+    let through = Point::new([3, 2]);
+    let line = Line::new_through(&origin, &through);
+    assert_eq!(line.distance_to_point(&Point::new([5, 3])), 0.0);
+    assert_eq!(line.distance_to_point(&origin), 0.0);
+  }
+
+  #[test]
+  fn distance_signed() {
+    let origin = Point::new([0, 0]);
+    let direction = Vector([2, 0]);
+    let line = Line::new_directed(&origin, &direction);
+    assert_eq!(line.distance_to_point(&Point::new([7, 3])), 3.0);
+    assert_eq!(line.distance_to_point(&Point::new([-7, -3])), -3.0);
+  }
+
+  #[test]
+  fn project_onto_x_axis() {
+    let origin = Point::new([0, 0]);
+    let through = Point::new([1, 0]);
+    let line = Line::new_through(&origin, &through);
+    assert_eq!(
+      line.project_point(&Point::new([5, 9])),
+      Point::new([5.0, 0.0])
+    );
+    assert_eq!(
+      line.project_point(&Point::new([-3, -2])),
+      Point::new([-3.0, 0.0])
+    );
+  }
+
+  #[proptest]
+  fn distance_sign_prop(a: Point<i8>, b: Point<i8>, p: Point<i8>) {
+    prop_assume!(a != b);
+    let line = Line::new_through(&a, &b);
+    let distance = line.distance_to_point(&p);
+    let expected = match Orientation::new(&a.array, &b.array, &p.array) {
+      Orientation::CounterClockWise => std::cmp::Ordering::Greater,
+      Orientation::ClockWise => std::cmp::Ordering::Less,
+      Orientation::CoLinear => std::cmp::Ordering::Equal,
+    };
+    prop_assert_eq!(distance.partial_cmp(&0.0), Some(expected));
+  }
+
+  #[proptest]
+  fn project_distance_prop(a: Point<i8>, b: Point<i8>, p: Point<i8>) {
+    prop_assume!(a != b);
+    let line = Line::new_through(&a, &b);
+    let foot = line.project_point(&p);
+    let distance: f64 = foot.squared_euclidean_distance::<f64>(&p.to_f64()).sqrt();
+    prop_assert!((distance - line.distance_to_point(&p).abs()).abs() < 1e-9);
+  }
+
   #[proptest]
   fn raw_intersection_count_prop(poly: Polygon<i8>, line: LineSoS_<i8>) {
     let line: LineSoS<'_, i8> = (&line).into();
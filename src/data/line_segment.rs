@@ -5,11 +5,15 @@ use std::cmp::PartialEq;
 use std::ops::Range;
 use std::ops::RangeInclusive;
 
+use num::BigRational;
+use num_traits::ToPrimitive;
+
+use super::Line;
 use super::Point;
 
 use crate::data::point::PointSoS;
 use crate::Intersects;
-use crate::{Orientation, PolygonScalar, TotalOrd};
+use crate::{Orientation, PolygonScalar, ToRational, TotalOrd};
 use Orientation::*;
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -117,7 +121,7 @@ fn inner_between<T: TotalOrd>(inner: &T, a: EndPoint<&T>, b: EndPoint<&T>) -> bo
 ///////////////////////////////////////////////////////////////////////////////
 // LineSegment
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LineSegment<T: TotalOrd, const N: usize = 2> {
   pub min: EndPoint<Point<T, N>>,
   pub max: EndPoint<Point<T, N>>,
@@ -224,6 +228,90 @@ impl<T: TotalOrd> LineSegmentView<'_, T> {
   }
 }
 
+impl<T: PolygonScalar> LineSegmentView<'_, T> {
+  /// Squared Euclidean length of the segment, computed exactly with the
+  /// arithmetic of `T`.
+  ///
+  /// Warning: May cause arithmetic overflow.
+  pub fn length_squared(&self) -> T {
+    let [x1, y1] = self.min.inner().array.clone();
+    let [x2, y2] = self.max.inner().array.clone();
+    let dx = x2 - x1;
+    let dy = y2 - y1;
+    dx.clone() * dx + dy.clone() * dy
+  }
+}
+
+impl<T: PolygonScalar + ToPrimitive> LineSegmentView<'_, T> {
+  /// Point halfway between the endpoints, in `f64`.
+  pub fn midpoint(&self) -> Point<f64> {
+    let [x1, y1] = self.min.inner().to_f64().array;
+    let [x2, y2] = self.max.inner().to_f64().array;
+    Point::new([(x1 + x2) / 2.0, (y1 + y2) / 2.0])
+  }
+
+  /// Point on the segment closest to `p`. Points beyond either end of the
+  /// segment are mapped to that endpoint. Computed in `f64`.
+  pub fn closest_point(&self, p: &Point<T>) -> Point<f64> {
+    let a = self.min.inner().to_f64();
+    let b = self.max.inner().to_f64();
+    let [dx, dy] = (b - a).0;
+    let [px, py] = (p.to_f64() - a).0;
+    let length_squared = dx * dx + dy * dy;
+    if length_squared == 0.0 {
+      return a;
+    }
+    let t = ((dx * px + dy * py) / length_squared).clamp(0.0, 1.0);
+    Point::new([a.array[0] + t * dx, a.array[1] + t * dy])
+  }
+
+  /// Split the segment into `n` pieces of equal length. Returns the `n + 1`
+  /// points from `min` to `max`, both endpoints included.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is zero.
+  pub fn subdivide(&self, n: usize) -> Vec<Point<f64>> {
+    assert!(n > 0, "cannot subdivide a segment into zero pieces");
+    let a = self.min.inner().to_f64();
+    let b = self.max.inner().to_f64();
+    let pieces = n.to_f64().unwrap();
+    (0..=n)
+      .map(|i| a.lerp(&b, i.to_f64().unwrap() / pieces))
+      .collect()
+  }
+
+  /// Sub-segment between the parameters `t0` and `t1`, where 0 is `min` and 1
+  /// is `max`. Parameters are clamped to `[0, 1]`. Ends that coincide with an
+  /// original endpoint keep its inclusive/exclusive flag, new ends are
+  /// inclusive.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let (a, b) = (Point::new([0, 0]), Point::new([8, 4]));
+  /// let segment = LineSegmentView::from(&a..&b);
+  /// let half = segment.clamp(0.5, 1.0);
+  /// assert_eq!(half.min, EndPoint::Inclusive(Point::new([4.0, 2.0])));
+  /// assert_eq!(half.max, EndPoint::Exclusive(Point::new([8.0, 4.0])));
+  /// ```
+  pub fn clamp(&self, t0: f64, t1: f64) -> LineSegment<f64> {
+    let a = self.min.inner().to_f64();
+    let b = self.max.inner().to_f64();
+    let end = |t: f64| {
+      let t = t.clamp(0.0, 1.0);
+      let pt = a.lerp(&b, t);
+      if (t == 0.0 && self.min.is_exclusive()) || (t == 1.0 && self.max.is_exclusive()) {
+        Exclusive(pt)
+      } else {
+        Inclusive(pt)
+      }
+    };
+    LineSegment::new(end(t0), end(t1))
+  }
+}
+
 impl<'a, T: TotalOrd, const N: usize> From<&'a Range<Point<T, N>>> for LineSegmentView<'a, T, N> {
   fn from(range: &'a Range<Point<T, N>>) -> LineSegmentView<'a, T, N> {
     LineSegmentView::new(
@@ -268,6 +356,86 @@ pub enum ILineSegment<'a, T: TotalOrd> {
   Overlap(LineSegmentView<'a, T>), // Lines touch and are parallel.
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// ILineSegmentExact
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ILineSegmentExact {
+  Crossing(Point<BigRational>),      // Lines touch at a single point.
+  Overlap(LineSegment<BigRational>), // Lines touch and are parallel.
+}
+
+impl<T: PolygonScalar> LineSegmentView<'_, T> {
+  /// Exact intersection of two line segments.
+  ///
+  /// Coordinates are promoted to [`BigRational`] so the crossing point is
+  /// never rounded, even when the input uses integer coordinates.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # use num::BigRational;
+  /// let (a, b) = (Point::new([0, 0]), Point::new([1, 1]));
+  /// let (c, d) = (Point::new([0, 1]), Point::new([2, 0]));
+  /// let ab = LineSegmentView::new(EndPoint::Inclusive(&a), EndPoint::Inclusive(&b));
+  /// let cd = LineSegmentView::new(EndPoint::Inclusive(&c), EndPoint::Inclusive(&d));
+  /// let third = BigRational::new(2.into(), 3.into());
+  /// assert_eq!(
+  ///   ab.intersection_point_exact(cd),
+  ///   Some(ILineSegmentExact::Crossing(Point::new([third.clone(), third])))
+  /// );
+  /// ```
+  pub fn intersection_point_exact(&self, other: LineSegmentView<'_, T>) -> Option<ILineSegmentExact>
+  where
+    T: ToRational,
+  {
+    let to_rational = |pt: &Point<T>| pt.map(|v| v.to_rational());
+    let to_endpoint = |end: EndPoint<&Point<T>>| match end {
+      Exclusive(pt) => Exclusive(to_rational(pt)),
+      Inclusive(pt) => Inclusive(to_rational(pt)),
+    };
+    match self.intersect(other)? {
+      ILineSegment::Overlap(overlap) => Some(ILineSegmentExact::Overlap(LineSegment::new(
+        to_endpoint(overlap.min),
+        to_endpoint(overlap.max),
+      ))),
+      ILineSegment::Crossing => {
+        let a1 = to_rational(self.min.inner());
+        let a2 = to_rational(self.max.inner());
+        let b1 = to_rational(other.min.inner());
+        let b2 = to_rational(other.max.inner());
+        Line::new_through(&a1, &a2)
+          .intersection_point(&Line::new_through(&b1, &b2))
+          .map(ILineSegmentExact::Crossing)
+      }
+    }
+  }
+}
+
+impl<'a, T: PolygonScalar> LineSegmentView<'a, T> {
+  /// Shared sub-segment of two colinear segments. Segments that only touch at
+  /// an inclusive endpoint share a degenerate sub-segment. Returns `None` if
+  /// the segments don't overlap or aren't colinear.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let (a, b) = (Point::new([0, 0]), Point::new([4, 0]));
+  /// let (c, d) = (Point::new([2, 0]), Point::new([6, 0]));
+  /// let ab = LineSegmentView::new(EndPoint::Inclusive(&a), EndPoint::Inclusive(&b));
+  /// let cd = LineSegmentView::new(EndPoint::Inclusive(&c), EndPoint::Inclusive(&d));
+  /// assert_eq!(ab.overlap(cd).unwrap().length_squared(), 4);
+  /// ```
+  pub fn overlap(&self, other: LineSegmentView<'a, T>) -> Option<LineSegmentView<'a, T>> {
+    match self.intersect(other)? {
+      ILineSegment::Overlap(overlap) => Some(overlap),
+      ILineSegment::Crossing => None,
+    }
+  }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Intersects
 
@@ -395,8 +563,183 @@ mod tests {
   use crate::Intersects;
   use ILineSegment::*;
 
+  use proptest::prelude::*;
   use test_strategy::proptest;
 
+  #[test]
+  fn length_and_midpoint() {
+    let a = Point::new([1, 2]);
+    let b = Point::new([4, 6]);
+    let range = a..=b;
+    let segment = LineSegmentView::from(&range);
+    assert_eq!(segment.length_squared(), 25);
+    assert_eq!(segment.midpoint(), Point::new([2.5, 4.0]));
+  }
+
+  #[test]
+  fn subdivide_endpoints() {
+    let a = Point::new([1, 2]);
+    let b = Point::new([4, 8]);
+    let range = a..=b;
+    let segment = LineSegmentView::from(&range);
+    assert_eq!(
+      segment.subdivide(1),
+      vec![Point::new([1.0, 2.0]), Point::new([4.0, 8.0])]
+    );
+    assert_eq!(
+      segment.subdivide(3),
+      vec![
+        Point::new([1.0, 2.0]),
+        Point::new([2.0, 4.0]),
+        Point::new([3.0, 6.0]),
+        Point::new([4.0, 8.0]),
+      ]
+    );
+  }
+
+  #[test]
+  fn clamp_whole_and_middle() {
+    let a = Point::new([0, 0]);
+    let b = Point::new([8, 4]);
+    let segment = LineSegmentView::from(&a..&b);
+    assert_eq!(
+      segment.clamp(0.0, 1.0),
+      LineSegment::new(
+        Inclusive(Point::new([0.0, 0.0])),
+        Exclusive(Point::new([8.0, 4.0]))
+      )
+    );
+    assert_eq!(
+      segment.clamp(0.25, 0.75),
+      LineSegment::new(
+        Inclusive(Point::new([2.0, 1.0])),
+        Inclusive(Point::new([6.0, 3.0]))
+      )
+    );
+    // Out of range parameters are clamped.
+    assert_eq!(segment.clamp(-1.0, 2.0), segment.clamp(0.0, 1.0));
+  }
+
+  #[test]
+  fn closest_point_interior() {
+    let a = Point::new([0, 0]);
+    let b = Point::new([10, 0]);
+    let range = a..=b;
+    let segment = LineSegmentView::from(&range);
+    assert_eq!(
+      segment.closest_point(&Point::new([3, 5])),
+      Point::new([3.0, 0.0])
+    );
+  }
+
+  #[test]
+  fn closest_point_clamped() {
+    let a = Point::new([0, 0]);
+    let b = Point::new([10, 0]);
+    let range = a..=b;
+    let segment = LineSegmentView::from(&range);
+    assert_eq!(
+      segment.closest_point(&Point::new([-4, 3])),
+      Point::new([0.0, 0.0])
+    );
+    assert_eq!(
+      segment.closest_point(&Point::new([12, -7])),
+      Point::new([10.0, 0.0])
+    );
+  }
+
+  #[proptest]
+  fn closest_point_prop(a: Point<i8>, b: Point<i8>, p: Point<i8>) {
+    let range = a..=b;
+    let segment = LineSegmentView::from(&range);
+    let p_f64 = p.to_f64();
+    let closest: f64 = segment.closest_point(&p).squared_euclidean_distance(&p_f64);
+    let to_a: f64 = a.to_f64().squared_euclidean_distance(&p_f64);
+    let to_b: f64 = b.to_f64().squared_euclidean_distance(&p_f64);
+    prop_assert!(closest <= to_a + 1e-6);
+    prop_assert!(closest <= to_b + 1e-6);
+  }
+
+  #[test]
+  fn exact_crossing() {
+    let a = Point::new([0, 0]);
+    let b = Point::new([1, 1]);
+    let c = Point::new([0, 1]);
+    let d = Point::new([2, 0]);
+    let ab = LineSegmentView::new(EndPoint::Inclusive(&a), EndPoint::Inclusive(&b));
+    let cd = LineSegmentView::new(EndPoint::Inclusive(&c), EndPoint::Inclusive(&d));
+    let third = BigRational::new(2.into(), 3.into());
+    let expected = Point::new([third.clone(), third]);
+    assert_eq!(
+      ab.intersection_point_exact(cd),
+      Some(ILineSegmentExact::Crossing(expected))
+    );
+    // Integer arithmetic truncates 2/3 to 0.
+    let approx = Line::new_through(&a, &b).intersection_point(&Line::new_through(&c, &d));
+    assert_eq!(approx, Some(Point::new([0, 0])));
+  }
+
+  #[test]
+  fn exact_crossing_third() {
+    let a = Point::new([0, 0]);
+    let b = Point::new([3, 1]);
+    let c = Point::new([1, 0]);
+    let d = Point::new([1, 1]);
+    let ab = LineSegmentView::new(EndPoint::Inclusive(&a), EndPoint::Inclusive(&b));
+    let cd = LineSegmentView::new(EndPoint::Inclusive(&c), EndPoint::Inclusive(&d));
+    let expected = Point::new([
+      BigRational::from_integer(1.into()),
+      BigRational::new(1.into(), 3.into()),
+    ]);
+    assert_eq!(
+      ab.intersection_point_exact(cd),
+      Some(ILineSegmentExact::Crossing(expected))
+    );
+  }
+
+  #[test]
+  fn exact_parallel() {
+    let a = Point::new([0, 0]);
+    let b = Point::new([2, 0]);
+    let c = Point::new([0, 1]);
+    let d = Point::new([2, 1]);
+    let ab = LineSegmentView::new(EndPoint::Inclusive(&a), EndPoint::Inclusive(&b));
+    let cd = LineSegmentView::new(EndPoint::Inclusive(&c), EndPoint::Inclusive(&d));
+    assert_eq!(ab.intersection_point_exact(cd), None);
+  }
+
+  #[test]
+  fn exact_overlap() {
+    let a = Point::new([0, 0]);
+    let b = Point::new([4, 0]);
+    let c = Point::new([2, 0]);
+    let d = Point::new([6, 0]);
+    let ab = LineSegmentView::new(EndPoint::Inclusive(&a), EndPoint::Inclusive(&b));
+    let cd = LineSegmentView::new(EndPoint::Inclusive(&c), EndPoint::Inclusive(&d));
+    let int = |v: i32| BigRational::from_integer(v.into());
+    let expected = LineSegment::new(
+      EndPoint::Inclusive(Point::new([int(2), int(0)])),
+      EndPoint::Inclusive(Point::new([int(4), int(0)])),
+    );
+    assert_eq!(
+      ab.intersection_point_exact(cd),
+      Some(ILineSegmentExact::Overlap(expected))
+    );
+  }
+
+  #[proptest]
+  fn exact_crossing_prop(a: Point<i8>, b: Point<i8>, c: Point<i8>, d: Point<i8>) {
+    let ab = LineSegmentView::new(EndPoint::Inclusive(&a), EndPoint::Inclusive(&b));
+    let cd = LineSegmentView::new(EndPoint::Inclusive(&c), EndPoint::Inclusive(&d));
+    if let Some(ILineSegmentExact::Crossing(pt)) = ab.intersection_point_exact(cd) {
+      let rational = |pt: &Point<i8>| pt.map(|v| v.to_rational());
+      let ab = rational(&a)..=rational(&b);
+      let cd = rational(&c)..=rational(&d);
+      prop_assert!(LineSegmentView::from(&ab).contains(&pt));
+      prop_assert!(LineSegmentView::from(&cd).contains(&pt));
+    }
+  }
+
   #[proptest]
   fn flip_intersects_prop(pts: [i8; 8]) {
     let [a, b, c, d, e, f, g, h] = pts;
@@ -494,6 +837,35 @@ mod tests {
     assert_eq!(l1.intersect(&l2), Some(Overlap(l3.as_ref())))
   }
 
+  #[test]
+  fn overlap_half() {
+    let l1 = LineSegment::from(Point::new([0, 0])..=Point::new([4, 4]));
+    let l2 = LineSegment::from(Point::new([2, 2])..=Point::new([6, 6]));
+    let overlap = l1.as_ref().overlap(l2.as_ref()).unwrap();
+    assert_eq!(overlap.min, Inclusive(&Point::new([2, 2])));
+    assert_eq!(overlap.max, Inclusive(&Point::new([4, 4])));
+    assert_eq!(overlap.length_squared(), 8);
+  }
+
+  #[test]
+  fn overlap_shared_endpoint() {
+    let l1 = LineSegment::from(Point::new([0, 0])..=Point::new([2, 0]));
+    let l2 = LineSegment::from(Point::new([2, 0])..=Point::new([5, 0]));
+    let overlap = l1.as_ref().overlap(l2.as_ref()).unwrap();
+    assert_eq!(overlap.min.inner(), overlap.max.inner());
+    assert_eq!(overlap.length_squared(), 0);
+  }
+
+  #[test]
+  fn overlap_disjoint() {
+    let l1 = LineSegment::from(Point::new([0, 0])..=Point::new([1, 0]));
+    let l2 = LineSegment::from(Point::new([2, 0])..=Point::new([5, 0]));
+    assert_eq!(l1.as_ref().overlap(l2.as_ref()), None);
+    let l3 = LineSegment::from(Point::new([0, 0])..=Point::new([2, 2]));
+    let l4 = LineSegment::from(Point::new([0, 2])..=Point::new([2, 0]));
+    assert_eq!(l3.as_ref().overlap(l4.as_ref()), None);
+  }
+
   #[test]
   fn edge_touch() {
     assert_eq!((P1..P7).intersect(&(P4..P2)), Some(Crossing))
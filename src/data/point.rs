@@ -10,6 +10,7 @@ use rand::Rng;
 use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
 use std::iter::Sum;
 use std::ops::Deref;
 use std::ops::Index;
@@ -17,8 +18,7 @@ use std::ops::Index;
 use super::{Direction, Vector};
 use crate::{Orientation, PolygonScalar, TotalOrd};
 
-#[allow(clippy::derived_hash_with_manual_eq)]
-#[derive(Debug, Clone, Copy, Hash)]
+#[derive(Debug, Clone, Copy)]
 #[repr(transparent)] // Required for correctness!
 pub struct Point<T, const N: usize = 2> {
   pub array: [T; N],
@@ -55,6 +55,16 @@ impl<T: TotalOrd, const N: usize> Ord for Point<T, N> {
   }
 }
 
+// Equality goes through 'TotalOrd' while hashing goes through 'Hash'. The two
+// agree for every scalar that implements both: 'OrderedFloat' and 'NotNan'
+// compare and hash +0.0 and -0.0 as the same value, and plain floats aren't
+// 'Hash'.
+impl<T: Hash, const N: usize> Hash for Point<T, N> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.array.hash(state)
+  }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct PointSoS<'a, T, const N: usize = 2> {
   pub index: u32,
@@ -172,6 +182,33 @@ impl<T, const N: usize> Point<T, N> {
   {
     self.map(|v| OrderedFloat(v.into()))
   }
+
+  // Lossy conversion for scalars that don't implement 'Into<f64>', such as i64 and BigInt.
+  pub(crate) fn to_f64(&self) -> Point<f64, N>
+  where
+    T: Clone + ToPrimitive,
+  {
+    self.map(|v| v.to_f64().unwrap())
+  }
+}
+
+impl<const N: usize> Point<f64, N> {
+  /// Linear interpolation between `self` (at `t = 0`) and `other` (at `t = 1`).
+  /// Values of `t` outside of `[0, 1]` extrapolate along the same line.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::Point;
+  /// let a = Point::new([0.0, 0.0]);
+  /// let b = Point::new([4.0, 2.0]);
+  /// assert_eq!(a.lerp(&b, 0.5), Point::new([2.0, 1.0]));
+  /// ```
+  pub fn lerp(&self, other: &Point<f64, N>, t: f64) -> Point<f64, N> {
+    Point {
+      array: array_init(|i| (1.0 - t) * self.array[i] + t * other.array[i]),
+    }
+  }
 }
 
 impl<T, const N: usize> Index<usize> for Point<T, N> {
@@ -302,16 +339,50 @@ impl<T: PolygonScalar> Point<T> {
     Orientation::along_perp_vector(p1, vector, p2)
   }
 
+  /// Check if `a`, `b`, and `c` lie on a common line. Coincident points are
+  /// always colinear.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::Point;
+  /// let a = Point::new([ 0, 0 ]);
+  /// let b = Point::new([ 1, 1 ]);
+  /// assert!(Point::collinear(&a, &b, &Point::new([ 3, 3 ])));
+  /// assert!(Point::collinear(&a, &a, &Point::new([ 3, 4 ])));
+  /// assert!(!Point::collinear(&a, &b, &Point::new([ 3, 4 ])));
+  /// ```
+  pub fn collinear(a: &Point<T, 2>, b: &Point<T, 2>, c: &Point<T, 2>) -> bool {
+    Orientation::new(a, b, c).is_colinear()
+  }
+
+  /// Check if every point in `pts` lies on a common line. Sets of fewer than
+  /// three distinct points are always colinear.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::Point;
+  /// let pts = [Point::new([ 0, 0 ]), Point::new([ 2, 1 ]), Point::new([ 4, 2 ])];
+  /// assert!(Point::all_colinear(&pts));
+  /// let pts = [Point::new([ 0, 0 ]), Point::new([ 0, 0 ]), Point::new([ 1, 2 ])];
+  /// assert!(Point::all_colinear(&pts));
+  /// let pts = [Point::new([ 0, 0 ]), Point::new([ 0, 0 ]), Point::new([ 1, 2 ]), Point::new([ 2, 2 ])];
+  /// assert!(!Point::all_colinear(&pts));
+  /// ```
   pub fn all_colinear(pts: &[Point<T>]) -> bool {
-    if pts.len() < 3 {
+    let Some(first) = pts.first() else {
       return true;
+    };
+    // Coincident points don't define a line, so use the first distinct point.
+    match pts.iter().find(|pt| *pt != first) {
+      None => true,
+      Some(second) => pts.iter().all(|pt| Point::collinear(first, second, pt)),
     }
-    pts
-      .iter()
-      .all(|pt| Point::orient(&pts[0], &pts[1], pt).is_colinear())
   }
 
-  /// Docs?
+  /// Compare the angles of `p` and `q` around `self`, counter-clockwise
+  /// starting from the positive x-axis. Points at the same angle are equal.
   pub fn ccw_cmp_around(&self, p: &Point<T, 2>, q: &Point<T, 2>) -> Ordering {
     self.ccw_cmp_around_with(&Vector([T::from_constant(1), T::from_constant(0)]), p, q)
   }
@@ -324,6 +395,62 @@ impl<T: PolygonScalar> Point<T> {
   ) -> Ordering {
     Orientation::ccw_cmp_around_with(z, self, p, q)
   }
+
+  /// Sort points counter-clockwise around `self`, starting from the
+  /// direction `start`. Points at the same angle are sorted by their distance
+  /// to `self`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let mut pts = [
+  ///   Point::new([0, -1]),
+  ///   Point::new([-1, 0]),
+  ///   Point::new([0, 1]),
+  ///   Point::new([1, 0]),
+  /// ];
+  /// Point::new([0, 0]).sort_around(&Vector([1, 0]), &mut pts);
+  /// assert_eq!(pts, [
+  ///   Point::new([1, 0]),
+  ///   Point::new([0, 1]),
+  ///   Point::new([-1, 0]),
+  ///   Point::new([0, -1]),
+  /// ]);
+  /// ```
+  pub fn sort_around(&self, start: &Vector<T, 2>, pts: &mut [Point<T, 2>]) {
+    pts.sort_by(|p, q| {
+      self
+        .ccw_cmp_around_with(start, p, q)
+        .then_with(|| self.cmp_distance_to(p, q))
+    });
+  }
+}
+
+impl<T: ToPrimitive> Point<T, 2> {
+  /// Average of a set of points.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `points` is empty.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::Point;
+  /// let pts = [Point::new([0, 0]), Point::new([4, 0]), Point::new([2, 3])];
+  /// assert_eq!(Point::centroid(&pts), Point::new([2.0, 1.0]));
+  /// ```
+  pub fn centroid(points: &[Point<T, 2>]) -> Point<f64, 2> {
+    assert!(!points.is_empty(), "centroid of an empty set of points");
+    let mut sum = [0.0, 0.0];
+    for pt in points {
+      sum[0] += pt.array[0].to_f64().unwrap();
+      sum[1] += pt.array[1].to_f64().unwrap();
+    }
+    let n = points.len().to_f64().unwrap();
+    Point::new([sum[0] / n, sum[1] / n])
+  }
 }
 
 // FIXME: Use a macro
@@ -366,12 +493,104 @@ mod sub;
 #[cfg(not(tarpaulin_include))]
 pub mod tests {
   use super::*;
+  use crate::data::{PointLocation, Polygon};
   use crate::testing::*;
   use crate::Orientation::*;
 
   use proptest::prelude::*;
   use test_strategy::proptest;
 
+  #[test]
+  fn signed_zero_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+      let mut hasher = DefaultHasher::new();
+      value.hash(&mut hasher);
+      hasher.finish()
+    }
+    let pos = Point::new([OrderedFloat(0.0), OrderedFloat(1.0)]);
+    let neg = Point::new([OrderedFloat(-0.0), OrderedFloat(1.0)]);
+    assert_eq!(pos, neg);
+    assert_eq!(hash_of(&pos), hash_of(&neg));
+    let pos = Point::new_nn([0.0, 1.0]);
+    let neg = Point::new_nn([-0.0, 1.0]);
+    assert_eq!(pos, neg);
+    assert_eq!(hash_of(&pos), hash_of(&neg));
+    let set: std::collections::HashSet<_> = [pos, neg].into_iter().collect();
+    assert_eq!(set.len(), 1);
+  }
+
+  #[test]
+  fn sort_around_axes() {
+    let mut pts = [
+      Point::new([0, -1]),
+      Point::new([-2, 0]),
+      Point::new([0, 1]),
+      Point::new([1, 0]),
+      Point::new([-1, 0]),
+    ];
+    Point::new([0, 0]).sort_around(&Vector([0, 1]), &mut pts);
+    assert_eq!(
+      pts,
+      [
+        Point::new([0, 1]),
+        Point::new([-1, 0]),
+        Point::new([-2, 0]),
+        Point::new([0, -1]),
+        Point::new([1, 0]),
+      ]
+    );
+  }
+
+  #[proptest]
+  fn sort_around_convex_prop(poly: crate::data::PolygonConvex<i8>) {
+    let vertices: Vec<Point<i32, 2>> = poly.iter().map(|pt| pt.cast()).collect();
+    let center = &vertices[0];
+    let start = &vertices[1] - center;
+    let mut sorted = vertices[1..].to_vec();
+    sorted.reverse();
+    center.sort_around(&start, &mut sorted);
+    prop_assert_eq!(&sorted[..], &vertices[1..]);
+  }
+
+  #[test]
+  fn centroid_square() {
+    let square = [
+      Point::new([0, 0]),
+      Point::new([4, 0]),
+      Point::new([4, 4]),
+      Point::new([0, 4]),
+    ];
+    assert_eq!(Point::centroid(&square), Point::new([2.0, 2.0]));
+    assert_eq!(Point::centroid(&square[1..2]), Point::new([4.0, 0.0]));
+  }
+
+  #[proptest]
+  fn centroid_inside_hull_prop(
+    #[strategy(proptest::collection::vec(any::<Point<i8, 2>>(), 1..50))] pts: Vec<Point<i8, 2>>,
+  ) {
+    let centroid = Point::centroid(&pts);
+    if let Ok(hull) = crate::algorithms::convex_hull(pts) {
+      let hull: Polygon<f64> = Polygon::new_unchecked(hull.iter().map(|pt| pt.cast()).collect());
+      prop_assert_eq!(hull.locate(&centroid), PointLocation::Inside);
+    }
+  }
+
+  #[test]
+  fn lerp_midpoint() {
+    let a = Point::new([1.0, -2.0]);
+    let b = Point::new([3.0, 4.0]);
+    assert_eq!(a.lerp(&b, 0.5), Point::new([2.0, 1.0]));
+  }
+
+  #[proptest]
+  fn lerp_endpoints_prop(a: Point<i32, 2>, b: Point<i32, 2>) {
+    let a: Point<f64, 2> = a.cast();
+    let b: Point<f64, 2> = b.cast();
+    prop_assert_eq!(a.lerp(&b, 0.0), a);
+    prop_assert_eq!(a.lerp(&b, 1.0), b);
+  }
+
   #[proptest]
   fn cmp_dist_i8_fuzz(pt1: Point<i8, 2>, pt2: Point<i8, 2>, pt3: Point<i8, 2>) {
     let pt1_big: Point<BigInt, 2> = pt1.cast();
@@ -383,6 +602,40 @@ pub mod tests {
     );
   }
 
+  fn rational(pt: &Point<f64, 2>) -> Point<BigRational, 2> {
+    pt.map(|v| BigRational::from_float(v).unwrap())
+  }
+
+  #[proptest]
+  fn cmp_dist_f64_fuzz(
+    #[strategy(any_nn::<2>())] pt1: Point<NotNan<f64>, 2>,
+    #[strategy(any_nn::<2>())] pt2: Point<NotNan<f64>, 2>,
+    #[strategy(any_nn::<2>())] pt3: Point<NotNan<f64>, 2>,
+  ) {
+    let [pt1, pt2, pt3] = [pt1, pt2, pt3].map(|pt| pt.map(|v| v.into_inner()));
+    prop_assert_eq!(
+      pt1.cmp_distance_to(&pt2, &pt3),
+      rational(&pt1).cmp_distance_to(&rational(&pt2), &rational(&pt3))
+    );
+  }
+
+  // Nearly equal distances exercise the exact fallback.
+  #[proptest]
+  fn cmp_dist_f64_near_tie_fuzz(pt1: Point<i8, 2>, pt2: Point<i8, 2>, eps: i8) {
+    let pt1: Point<f64, 2> = pt1.map(|v| v as f64);
+    let pt2: Point<f64, 2> = pt2.map(|v| v as f64);
+    // Reflect pt2 through pt1 and nudge it by a tiny amount.
+    let nudge = eps as f64 * 256.0 * f64::EPSILON;
+    let pt3 = Point::new([
+      2.0 * pt1.array[0] - pt2.array[0] + nudge,
+      2.0 * pt1.array[1] - pt2.array[1],
+    ]);
+    prop_assert_eq!(
+      pt1.cmp_distance_to(&pt2, &pt3),
+      rational(&pt1).cmp_distance_to(&rational(&pt2), &rational(&pt3))
+    );
+  }
+
   #[proptest]
   fn squared_euclidean_distance_fuzz(
     #[strategy(any_nn::<2>())] pt1: Point<NotNan<f64>, 2>,
@@ -458,6 +711,23 @@ pub mod tests {
     )
   }
 
+  // Once consecutive duplicates are removed, every pair of neighbours defines
+  // the same line exactly when all the points are colinear.
+  #[proptest]
+  fn all_colinear_prop(
+    #[strategy(proptest::collection::vec((0..4_i8, 0..4_i8), 0..8))] coords: Vec<(i8, i8)>,
+  ) {
+    let mut pts: Vec<Point<i8>> = coords
+      .into_iter()
+      .map(|(x, y)| Point::new([x, y]))
+      .collect();
+    pts.dedup();
+    let consecutive = pts
+      .windows(3)
+      .all(|w| Point::collinear(&w[0], &w[1], &w[2]));
+    prop_assert_eq!(Point::all_colinear(&pts), consecutive);
+  }
+
   #[proptest]
   fn orientation_reverse(pt1: Point<i64, 2>, pt2: Point<i64, 2>, pt3: Point<i64, 2>) {
     let abc = Point::orient(&pt1, &pt2, &pt3);
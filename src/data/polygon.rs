@@ -1,13 +1,15 @@
 // use claim::debug_assert_ok;
 use num_traits::*;
 use ordered_float::OrderedFloat;
+use rand::Rng;
 use std::cmp::Ordering;
 use std::iter::Sum;
 use std::ops::Bound::*;
 use std::ops::*;
 
 use crate::data::{
-  DirectedEdge, HalfLineSoS, IHalfLineLineSegmentSoS::*, Point, PointLocation, TriangleView, Vector,
+  DirectedEdge, Direction, EndPoint, HalfLineSoS, IHalfLineLineSegmentSoS::*, ILineSegment, Line,
+  LineSegmentView, Point, PointLocation, TriangleView, Vector,
 };
 use crate::intersection::*;
 use crate::{Error, Orientation, PolygonScalar, TotalOrd};
@@ -18,7 +20,7 @@ pub use iter::*;
 mod convex;
 pub use convex::*;
 
-use super::Transform;
+use super::TransformBuilder;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct PositionId(usize);
@@ -39,7 +41,7 @@ impl From<RingId> for usize {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct PointId(usize);
+pub struct PointId(pub(crate) usize);
 
 impl From<PointId> for usize {
   fn from(pid: PointId) -> usize {
@@ -156,6 +158,46 @@ impl<T> Polygon<T> {
     Ok(p)
   }
 
+  /// Like [`new`](Self::new) but the winding of `points` must match
+  /// `expected` instead of being silently fixed. Clockwise input is accepted
+  /// when `expected` is clockwise and is then stored counter-clockwise like
+  /// every other polygon.
+  ///
+  /// Returns [`Error::ClockWiseViolation`] if the winding doesn't match and
+  /// [`Error::CoLinearViolation`] if the points have no winding.
+  ///
+  /// $O(n^2)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # use rgeometry::{Error, Orientation};
+  /// let pts = vec![Point::new([0, 0]), Point::new([0, 1]), Point::new([1, 0])];
+  /// assert_eq!(
+  ///   Polygon::new_oriented(pts.clone(), Orientation::CounterClockWise).err(),
+  ///   Some(Error::ClockWiseViolation)
+  /// );
+  /// assert!(Polygon::new_oriented(pts, Orientation::ClockWise).is_ok());
+  /// ```
+  pub fn new_oriented(points: Vec<Point<T, 2>>, expected: Orientation) -> Result<Polygon<T>, Error>
+  where
+    T: PolygonScalar,
+  {
+    if points.len() < 3 {
+      return Err(Error::InsufficientVertices);
+    }
+    let mut p = Self::new_unchecked(points);
+    match p.orientation() {
+      Orientation::CoLinear => return Err(Error::CoLinearViolation),
+      orientation if orientation != expected => return Err(Error::ClockWiseViolation),
+      _ => {}
+    }
+    p.ensure_ccw()?;
+    p.validate()?;
+    Ok(p)
+  }
+
   // Validate that a polygon is simple.
   // https://en.wikipedia.org/wiki/Simple_polygon
   pub fn validate(&self) -> Result<(), Error>
@@ -199,243 +241,958 @@ impl<T> Polygon<T> {
     Ok(())
   }
 
-  pub fn locate(&self, origin: &Point<T, 2>) -> PointLocation
+  /// First boundary edge hit by a ray.
+  ///
+  /// Returns the edge, the point where the ray meets it, and the parametric
+  /// distance `t` such that the hit point is `origin + t * direction`. Hits are
+  /// detected exactly with the same simulation of simplicity as
+  /// [`locate`](Self::locate): a ray passing through a vertex hits only one
+  /// of the two adjacent edges. Edges of holes are included.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let square = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([4, 0]),
+  ///   Point::new([4, 4]),
+  ///   Point::new([0, 4]),
+  /// ])?;
+  /// let origin = Point::new([1, 2]);
+  /// let direction = Vector([1, 0]);
+  /// let (_edge, hit, t) = square.ray_cast(&HalfLineSoS::new_directed(&origin, &direction)).unwrap();
+  /// assert_eq!(hit, Point::new([4.0, 2.0]));
+  /// assert_eq!(t, 3.0);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn ray_cast(&self, ray: &HalfLineSoS<'_, T>) -> Option<(IndexEdge, Point<f64>, f64)>
   where
-    T: PolygonScalar,
+    T: PolygonScalar + ToPrimitive,
   {
-    // FIXME: Support polygons with holes.
-    assert_eq!(
-      self.rings.len(),
-      1,
-      "FIXME: Polygon::locate should support polygons with holes."
-    );
-
-    let direction = Vector::unit_right();
-    let ray = HalfLineSoS::new_directed(origin, &direction);
-    let mut intersections = 0;
-    for edge in self.iter_boundary_edges() {
-      if edge.contains(origin) {
-        return PointLocation::OnBoundary;
-      }
-      if let Some(Crossing(lean)) = ray.intersect(edge) {
-        // Only count crossing that aren't leaning to the right.
-        if !lean.is_cw() {
-          intersections += 1;
+    let line = Line::from(*ray);
+    let origin = line.origin.to_f64();
+    let direction: Vector<f64, 2> = match line.direction {
+      Direction::Vector(v) => Vector(v.0.clone().map(|c| c.to_f64().unwrap())),
+      Direction::Through(pt) => pt.to_f64() - origin,
+    };
+    let cross = |u: &Vector<f64, 2>, v: &Vector<f64, 2>| u.0[0] * v.0[1] - u.0[1] * v.0[0];
+    let mut best: Option<(IndexEdge, Point<f64>, f64)> = None;
+    for ring in &self.rings {
+      for (i, &a) in ring.iter().enumerate() {
+        let b = ring[(i + 1) % ring.len()];
+        let segment = LineSegmentView::new(
+          EndPoint::Inclusive(self.point(a)),
+          EndPoint::Inclusive(self.point(b)),
+        );
+        if ray.intersect(segment).is_none() {
+          continue;
+        }
+        let pa = self.point(a).to_f64();
+        let edge = self.point(b).to_f64() - pa;
+        let t = cross(&(pa - origin), &edge) / cross(&direction, &edge);
+        if best.as_ref().map_or(true, |(_, _, best_t)| t < *best_t) {
+          let hit = Point::new([
+            origin.array[0] + t * direction.0[0],
+            origin.array[1] + t * direction.0[1],
+          ]);
+          best = Some((IndexEdge::new(a, b), hit, t));
         }
       }
     }
-    if intersections % 2 == 0 {
-      PointLocation::Outside
-    } else {
-      PointLocation::Inside
-    }
+    best
   }
 
-  pub fn triangulate(
-    &self,
-  ) -> impl Iterator<Item = (Cursor<'_, T>, Cursor<'_, T>, Cursor<'_, T>)> + '_
+  /// Boundary edge closest to `p` and the point on that edge nearest to `p`.
+  /// Edges of holes are included. Ties are broken in favor of the first edge.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let square = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([4, 0]),
+  ///   Point::new([4, 4]),
+  ///   Point::new([0, 4]),
+  /// ])?;
+  /// let (_edge, snapped) = square.nearest_boundary_point(&Point::new([5, 1]));
+  /// assert_eq!(snapped, Point::new([4.0, 1.0]));
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn nearest_boundary_point(&self, p: &Point<T, 2>) -> (IndexEdge, Point<f64, 2>)
   where
-    T: PolygonScalar,
+    T: PolygonScalar + ToPrimitive,
   {
-    crate::algorithms::triangulation::earclip::earclip(self)
-      .map(move |(p1, p2, p3)| (self.cursor(p1), self.cursor(p2), self.cursor(p3)))
+    let target = p.to_f64();
+    let mut best: Option<(IndexEdge, Point<f64>, f64)> = None;
+    for ring in &self.rings {
+      for (i, &a) in ring.iter().enumerate() {
+        let b = ring[(i + 1) % ring.len()];
+        let segment = LineSegmentView::new(
+          EndPoint::Inclusive(self.point(a)),
+          EndPoint::Inclusive(self.point(b)),
+        );
+        let closest = segment.closest_point(p);
+        let distance = (closest - target).squared_magnitude();
+        if best.as_ref().map_or(true, |(_, _, d)| distance < *d) {
+          best = Some((IndexEdge::new(a, b), closest, distance));
+        }
+      }
+    }
+    let (edge, closest, _) = best.expect("polygons have at least one edge");
+    (edge, closest)
   }
 
-  //
-  // # Panics
-  //
-  // May panic for bounded types (i8, isize, etc). Maybe produce inaccurate results for
-  // floating point types (f32, f64).
-  pub fn centroid(&self) -> Point<T>
+  /// Pairs of edges that cross at a point inside both edges, or that overlap
+  /// along more than a single point. Edges of holes are included. Edges that
+  /// only touch, for example at a shared vertex or where a vertex lies on
+  /// another edge, are not reported, so a polygon with an empty result may
+  /// still fail [`validate`](Self::validate).
+  ///
+  /// Each pair is reported once with the smaller edge first, and the pairs are
+  /// sorted.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n^2)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let bowtie = Polygon::new_unchecked(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([2, 2]),
+  ///   Point::new([2, 0]),
+  ///   Point::new([0, 2]),
+  /// ]);
+  /// assert_eq!(bowtie.self_intersections().len(), 1);
+  /// ```
+  pub fn self_intersections(&self) -> Vec<(IndexEdge, IndexEdge)>
   where
     T: PolygonScalar,
   {
-    let xs: Vector<T, 2> = self
-      .iter_boundary_edges()
-      .map(|edge| {
-        let p = edge.src.as_vec();
-        let q = edge.dst.as_vec();
-        (p + q) * (p.0[0].clone() * q.0[1].clone() - q.0[0].clone() * p.0[1].clone())
-      })
-      .sum();
-    let three = T::from_constant(3);
-    Point::from(xs / (three * self.signed_area_2x()))
+    let edges: Vec<IndexEdge> = self
+      .rings
+      .iter()
+      .flat_map(|ring| (0..ring.len()).map(|i| IndexEdge::new(ring[i], ring[(i + 1) % ring.len()])))
+      .collect();
+    let mut pairs = Vec::new();
+    for (i, &a) in edges.iter().enumerate() {
+      for &b in &edges[i + 1..] {
+        if self.edges_cross(a, b) {
+          pairs.push(if a < b { (a, b) } else { (b, a) });
+        }
+      }
+    }
+    pairs.sort();
+    pairs
   }
 
-  pub fn bounding_box(&self) -> (Point<T>, Point<T>)
+  // True if the edges meet at a point inside both of them or overlap along
+  // more than a point.
+  fn edges_cross(&self, a: IndexEdge, b: IndexEdge) -> bool
   where
     T: PolygonScalar,
   {
-    assert!(!self.rings[0].is_empty());
-    let mut min_x = self.points[self.rings[0][0].usize()].x_coord().clone();
-    let mut max_x = self.points[self.rings[0][0].usize()].x_coord().clone();
-    let mut min_y = self.points[self.rings[0][0].usize()].y_coord().clone();
-    let mut max_y = self.points[self.rings[0][0].usize()].y_coord().clone();
-    for pt in self.iter_boundary() {
-      if pt.point().x_coord() < &min_x {
-        min_x = pt.point().x_coord().clone();
-      }
-      if pt.point().x_coord() > &max_x {
-        max_x = pt.point().x_coord().clone();
-      }
-      if pt.point().y_coord() < &min_y {
-        min_y = pt.point().y_coord().clone();
-      }
-      if pt.point().y_coord() > &max_y {
-        max_y = pt.point().y_coord().clone();
-      }
+    let (a1, a2) = (self.point(a.min), self.point(a.max));
+    let (b1, b2) = (self.point(b.min), self.point(b.max));
+    let sides = [
+      Point::orient(a1, a2, b1),
+      Point::orient(a1, a2, b2),
+      Point::orient(b1, b2, a1),
+      Point::orient(b1, b2, a2),
+    ];
+    if sides.iter().all(|side| side.is_colinear()) {
+      let segment = |p, q| LineSegmentView::new(EndPoint::Inclusive(p), EndPoint::Inclusive(q));
+      matches!(
+        segment(a1, a2).intersect(segment(b1, b2)),
+        Some(ILineSegment::Overlap(overlap)) if overlap.min.inner() != overlap.max.inner()
+      )
+    } else {
+      !sides.iter().any(|side| side.is_colinear()) && sides[0] != sides[1] && sides[2] != sides[3]
     }
-    (Point::new([min_x, min_y]), Point::new([max_x, max_y]))
   }
 
-  /// Computes the area of a polygon. If the polygon winds counter-clockwise,
-  /// the area will be a positive number. If the polygon winds clockwise, the area will
-  /// be negative.
-  ///
-  /// # Return type
-  ///
-  /// This function is polymorphic for the same reason as [`signed_area_2x`](Polygon::signed_area_2x).
+  /// Check that no two boundary edges intersect, using the same rules as
+  /// [`validate_weakly`](Self::validate_weakly). Orientation and the number of
+  /// vertices are not checked, and like `validate_weakly` only the outer
+  /// boundary is checked. Stops at the first intersection.
   ///
   /// # Time complexity
   ///
-  /// $O(n)$
+  /// $O(n \log n)$
   ///
   /// # Examples
-  /// ```rust
-  /// // The area of this polygon is 1/2 and won't fit in an `i32`
-  /// # use rgeometry::data::*;
-  /// # fn main() -> Result<(), rgeometry::Error> {
-  /// let p = Polygon::new(vec![
-  ///   Point::new([0, 0]),
-  ///   Point::new([1, 0]),
-  ///   Point::new([0, 1]),
-  ///  ])?;
-  /// assert_eq!(p.signed_area::<i32>(), 0);
-  /// # Ok(())
-  /// # }
-  /// ```
   ///
   /// ```rust
-  /// // The area of this polygon is 1/2 and will fit in a `Ratio<i32>`.
   /// # use rgeometry::data::*;
-  /// # use num_rational::Ratio;
-  /// # fn main() -> Result<(), rgeometry::Error> {
-  /// let p = Polygon::new(vec![
+  /// let bowtie = Polygon::new_unchecked(vec![
   ///   Point::new([0, 0]),
-  ///   Point::new([1, 0]),
-  ///   Point::new([0, 1]),
-  ///  ])?;
-  /// assert_eq!(p.signed_area::<Ratio<i32>>(), Ratio::from((1,2)));
-  /// # Ok(())
-  /// # }
+  ///   Point::new([2, 2]),
+  ///   Point::new([2, 0]),
+  ///   Point::new([0, 2]),
+  /// ]);
+  /// assert!(!bowtie.is_simple_fast());
   /// ```
-  pub fn signed_area<F>(&self) -> F
+  pub fn is_simple_fast(&self) -> bool
   where
-    T: PolygonScalar + Into<F>,
-    F: NumOps<F, F> + Sum + FromPrimitive,
+    T: PolygonScalar,
   {
-    self.signed_area_2x::<F>() / F::from_usize(2).unwrap()
+    let ring: Vec<&Point<T>> = self.iter_boundary().map(|cursor| cursor.point()).collect();
+    !crate::algorithms::intersection::sweep::polyline_intersects(&ring)
   }
 
-  /// Compute double the area of a polygon. If the polygon winds counter-clockwise,
-  /// the area will be a positive number. If the polygon winds clockwise, the area will
-  /// be negative.
-  ///
-  /// Why compute double the area? If you are using integer coordinates then the doubled
-  /// area will also be an integer value. Computing the exact requires a division which
-  /// may leave you with a non-integer result.
-  ///
-  /// # Return type
-  ///
-  /// Storing the area of a polygon may require more bits of precision than are used
-  /// for the coordinates. For example, if 32bit integers are used for point coordinates
-  /// then you might need 65 bits to store the area doubled. For this reason, the return
-  /// type is polymorphic and should be selected with care. If you are unsure which type
-  /// is appropriate, use [`BigInt`](num::BigInt) or [`BigRational`](num::BigRational).
+  /// Squared lengths of the boundary edges, starting with the edge from the
+  /// first boundary vertex.
   ///
-  /// # Time complexity
+  /// # Panics
   ///
-  /// $O(n)$
+  /// May panic for bounded types (i8, isize, etc) if squared lengths overflow.
   ///
-  /// # Examples:
-  /// ```rust
-  /// // The area of this polygon is 1/2 and cannot fit in an `i32` variable
-  /// // so lets compute twice the area.
-  /// # use rgeometry::data::*;
-  /// # fn main() -> Result<(), rgeometry::Error> {
-  /// let p = Polygon::new(vec![
-  ///   Point::new([0, 0]),
-  ///   Point::new([1, 0]),
-  ///   Point::new([0, 1]),
-  ///  ])?;
-  /// assert_eq!(p.signed_area_2x::<i32>(), 1);
-  /// # Ok(())
-  /// # }
-  /// ```
+  /// # Examples
   ///
   /// ```rust
-  /// // The area of a polygon may require more bits of precision than are
-  /// // used for the coordinates.
-  /// # use num::BigInt;
   /// # use rgeometry::data::*;
-  /// # fn main() -> Result<(), rgeometry::Error> {
   /// let p = Polygon::new(vec![
   ///   Point::new([0, 0]),
-  ///   Point::new([i32::MAX, 0]),
-  ///   Point::new([0, i32::MAX]),
-  ///  ])?;
-  /// assert_eq!(p.signed_area_2x::<i64>(), 4611686014132420609_i64);
-  /// # Ok(())
-  /// # }
+  ///   Point::new([3, 0]),
+  ///   Point::new([3, 4]),
+  /// ]).unwrap();
+  /// assert_eq!(p.edge_lengths_squared(), vec![9, 16, 25]);
   /// ```
-  pub fn signed_area_2x<F>(&self) -> F
+  pub fn edge_lengths_squared(&self) -> Vec<T>
   where
-    T: PolygonScalar + Into<F>,
-    F: NumOps<F, F> + Sum,
+    T: PolygonScalar,
   {
     self
-      .iter_boundary_edges()
-      .map(|edge| {
-        let p = edge.src;
-        let q = edge.dst;
-        p.array[0].clone().into() * q.array[1].clone().into()
-          - q.array[0].clone().into() * p.array[1].clone().into()
+      .iter_boundary()
+      .map(|cursor| {
+        cursor
+          .point()
+          .squared_euclidean_distance::<T>(cursor.next().point())
       })
-      .sum()
+      .collect()
   }
 
-  pub fn orientation(&self) -> Orientation
+  /// Longest boundary edge and its squared length. Ties are broken in favor
+  /// of the first edge in boundary order.
+  ///
+  /// # Panics
+  ///
+  /// May panic for bounded types (i8, isize, etc) if squared lengths overflow.
+  pub fn longest_edge(&self) -> (IndexEdge, T)
   where
     T: PolygonScalar,
   {
-    match self.iter_boundary().min_by(|a, b| a.point().cmp(b.point())) {
-      None => Orientation::CoLinear,
-      Some(cursor) => cursor.orientation(),
-    }
+    self.extreme_edge(Ordering::Greater)
   }
 
-  /// Access point of a given vertex.
-  /// # Time complexity
-  /// $O(1)$
-  pub fn point(&self, idx: PointId) -> &Point<T> {
-    &self.points[idx.0]
+  /// Shortest boundary edge and its squared length. Ties are broken in favor
+  /// of the first edge in boundary order.
+  ///
+  /// # Panics
+  ///
+  /// May panic for bounded types (i8, isize, etc) if squared lengths overflow.
+  pub fn shortest_edge(&self) -> (IndexEdge, T)
+  where
+    T: PolygonScalar,
+  {
+    self.extreme_edge(Ordering::Less)
   }
 
-  // FIXME: The PointId may not be valid. Return Option<Cursor>.
-  /// Access cursor of a given vertex.
-  /// # Time complexity
-  /// $O(1)$
-  pub fn cursor(&self, idx: PointId) -> Cursor<'_, T> {
-    let ring_id = self.ring_index[idx.0];
-    let position_id = self.position_index[idx.0];
-    Cursor {
-      polygon: self,
-      position: Position {
-        ring_id,
-        position_id,
-        size: self.rings[ring_id.0].len(),
-      },
-    }
+  fn extreme_edge(&self, wanted: Ordering) -> (IndexEdge, T)
+  where
+    T: PolygonScalar,
+  {
+    self
+      .iter_boundary()
+      .map(|cursor| {
+        let next = cursor.next();
+        let edge = IndexEdge::new(cursor.point_id(), next.point_id());
+        (
+          edge,
+          cursor.point().squared_euclidean_distance::<T>(next.point()),
+        )
+      })
+      .reduce(|best, candidate| {
+        if candidate.1.total_cmp(&best.1) == wanted {
+          candidate
+        } else {
+          best
+        }
+      })
+      .expect("polygons have at least one edge")
+  }
+
+  pub fn locate(&self, origin: &Point<T, 2>) -> PointLocation
+  where
+    T: PolygonScalar,
+  {
+    // FIXME: Support polygons with holes.
+    assert_eq!(
+      self.rings.len(),
+      1,
+      "FIXME: Polygon::locate should support polygons with holes."
+    );
+
+    let direction = Vector::unit_right();
+    let ray = HalfLineSoS::new_directed(origin, &direction);
+    let mut intersections = 0;
+    for edge in self.iter_boundary_edges() {
+      if edge.contains(origin) {
+        return PointLocation::OnBoundary;
+      }
+      if let Some(Crossing(lean)) = ray.intersect(edge) {
+        // Only count crossing that aren't leaning to the right.
+        if !lean.is_cw() {
+          intersections += 1;
+        }
+      }
+    }
+    if intersections % 2 == 0 {
+      PointLocation::Outside
+    } else {
+      PointLocation::Inside
+    }
+  }
+
+  /// Like [`locate`](Self::locate) for floating point queries that may be
+  /// slightly off. Points within `eps` of an edge are on the boundary, all
+  /// other points are located with the usual ray cast. Holes are supported.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let square = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([4, 0]),
+  ///   Point::new([4, 4]),
+  ///   Point::new([0, 4]),
+  /// ])?;
+  /// let p = Point::new([4.0 + 1e-12, 2.0]);
+  /// assert_eq!(square.locate_with_tolerance(&p, 1e-9), PointLocation::OnBoundary);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn locate_with_tolerance(&self, p: &Point<f64, 2>, eps: f64) -> PointLocation
+  where
+    T: PolygonScalar + ToPrimitive,
+  {
+    let direction = Vector::unit_right();
+    let ray = HalfLineSoS::new_directed(p, &direction);
+    let mut intersections = 0;
+    for ring in &self.rings {
+      for (i, &src) in ring.iter().enumerate() {
+        let src = self.point(src).to_f64();
+        let dst = self.point(ring[(i + 1) % ring.len()]).to_f64();
+        let edge = LineSegmentView::new(EndPoint::Inclusive(&src), EndPoint::Inclusive(&dst));
+        if (edge.closest_point(p) - *p).squared_magnitude() <= eps * eps {
+          return PointLocation::OnBoundary;
+        }
+        if let Some(Crossing(lean)) = ray.intersect(edge) {
+          // Only count crossing that aren't leaning to the right.
+          if !lean.is_cw() {
+            intersections += 1;
+          }
+        }
+      }
+    }
+    if intersections % 2 == 0 {
+      PointLocation::Outside
+    } else {
+      PointLocation::Inside
+    }
+  }
+
+  /// Like [`locate`](Self::locate) but points on the boundary are resolved to
+  /// inside or outside. Returns true if the point is inside.
+  ///
+  /// Uses simulation of simplicity: `origin` is moved by `(ε, ε²)` for an
+  /// infinitely small `ε`, that is a tiny bit to the right and an even tinier
+  /// bit up. Perturbed points are never on the boundary. For a
+  /// counter-clockwise polygon, points on bottom and left edges are inside and
+  /// points on top and right edges are outside. Holes are supported.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let square = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([4, 0]),
+  ///   Point::new([4, 4]),
+  ///   Point::new([0, 4]),
+  /// ])?;
+  /// assert!(square.locate_sos(&Point::new([2, 0])));
+  /// assert!(!square.locate_sos(&Point::new([2, 4])));
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn locate_sos(&self, origin: &Point<T, 2>) -> bool
+  where
+    T: PolygonScalar,
+  {
+    // Orientation of the perturbed origin relative to the edge from 'src' to
+    // 'dst'. The ε term is -dy and the ε² term is dx.
+    let orient = |src: &Point<T>, dst: &Point<T>| {
+      let by_dy = match dst.y_coord().total_cmp(src.y_coord()) {
+        Ordering::Less => Orientation::CounterClockWise,
+        Ordering::Greater => Orientation::ClockWise,
+        Ordering::Equal => Orientation::CoLinear,
+      };
+      let by_dx = match dst.x_coord().total_cmp(src.x_coord()) {
+        Ordering::Greater => Orientation::CounterClockWise,
+        Ordering::Less => Orientation::ClockWise,
+        Ordering::Equal => Orientation::CoLinear,
+      };
+      Point::orient(src, dst, origin).then(by_dy).then(by_dx)
+    };
+    // The perturbed origin is above every vertex at the same height.
+    let above = |pt: &Point<T>| pt.y_coord() > origin.y_coord();
+    let mut winding = 0;
+    for ring in &self.rings {
+      for (i, &src) in ring.iter().enumerate() {
+        let src = self.point(src);
+        let dst = self.point(ring[(i + 1) % ring.len()]);
+        if !above(src) && above(dst) && orient(src, dst).is_ccw() {
+          winding += 1;
+        } else if above(src) && !above(dst) && orient(src, dst).is_cw() {
+          winding -= 1;
+        }
+      }
+    }
+    winding != 0
+  }
+
+  /// Number of times the boundary winds counter-clockwise around `origin`.
+  ///
+  /// Unlike [`locate`](Polygon::locate), this works for self-intersecting
+  /// and self-overlapping rings. Clockwise windings count negatively, so the
+  /// winding number is 0 inside a hole. The result is unspecified for points
+  /// on the boundary.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// // A pentagram winds twice around its center.
+  /// let star = Polygon::new_unchecked(vec![
+  ///   Point::new([0, 10]),
+  ///   Point::new([-6, -8]),
+  ///   Point::new([10, 3]),
+  ///   Point::new([-10, 3]),
+  ///   Point::new([6, -8]),
+  /// ]);
+  /// assert_eq!(star.winding_number(&Point::new([0, 0])), 2);
+  /// assert_eq!(star.winding_number(&Point::new([0, 8])), 1);
+  /// assert_eq!(star.winding_number(&Point::new([20, 0])), 0);
+  /// ```
+  pub fn winding_number(&self, origin: &Point<T, 2>) -> i32
+  where
+    T: PolygonScalar,
+  {
+    let mut winding = 0;
+    for ring in &self.rings {
+      for (i, &src) in ring.iter().enumerate() {
+        let src = self.point(src);
+        let dst = self.point(ring[(i + 1) % ring.len()]);
+        if src.y_coord() <= origin.y_coord() {
+          // Upward crossing with the origin to the left.
+          if dst.y_coord() > origin.y_coord() && Point::orient(src, dst, origin).is_ccw() {
+            winding += 1;
+          }
+        } else if dst.y_coord() <= origin.y_coord() && Point::orient(src, dst, origin).is_cw() {
+          // Downward crossing with the origin to the right.
+          winding -= 1;
+        }
+      }
+    }
+    winding
+  }
+
+  pub fn triangulate(
+    &self,
+  ) -> impl Iterator<Item = (Cursor<'_, T>, Cursor<'_, T>, Cursor<'_, T>)> + '_
+  where
+    T: PolygonScalar,
+  {
+    crate::algorithms::triangulation::earclip::earclip(self)
+      .map(move |(p1, p2, p3)| (self.cursor(p1), self.cursor(p2), self.cursor(p3)))
+  }
+
+  /// Ear-clipping triangulation from [`triangulate`](Self::triangulate) as
+  /// point ids. Each triangle is counter-clockwise.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the polygon has holes.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n^2)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let p = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([2, 0]),
+  ///   Point::new([2, 2]),
+  ///   Point::new([0, 2]),
+  /// ]).unwrap();
+  /// assert_eq!(p.triangulate_indices().len(), 2);
+  /// ```
+  pub fn triangulate_indices(&self) -> Vec<[PointId; 3]>
+  where
+    T: PolygonScalar,
+  {
+    crate::algorithms::triangulation::earclip::earclip(self)
+      .map(|(a, b, c)| [a, b, c])
+      .collect()
+  }
+
+  /// Minkowski sum with a disk of the given radius. See
+  /// [`buffer_rounded`](crate::algorithms::offset::buffer_rounded).
+  pub fn buffer_rounded(&self, radius: f64, arc_segments: usize) -> Polygon<f64>
+  where
+    T: PolygonScalar + ToPrimitive,
+  {
+    crate::algorithms::offset::buffer_rounded(self, radius, arc_segments)
+  }
+
+  /// Internal diagonals of the ear-clipping triangulation from
+  /// [`triangulate`](Self::triangulate). A polygon with `n` vertices has
+  /// `n - 3` diagonals.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the polygon has holes.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n^2)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let p = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([2, 0]),
+  ///   Point::new([2, 2]),
+  ///   Point::new([0, 2]),
+  /// ]).unwrap();
+  /// assert_eq!(p.triangulation_diagonals().len(), 1);
+  /// ```
+  pub fn triangulation_diagonals(&self) -> Vec<IndexEdge>
+  where
+    T: PolygonScalar,
+  {
+    let is_boundary = |a: PointId, b: PointId| {
+      let cursor = self.cursor(a);
+      cursor.next().point_id() == b || cursor.prev().point_id() == b
+    };
+    // Each ear is cut off along the edge between its two neighbours.
+    let diagonals: std::collections::BTreeSet<IndexEdge> =
+      crate::algorithms::triangulation::earclip::earclip(self)
+        .filter(|&(prev, _ear, next)| !is_boundary(prev, next))
+        .map(|(prev, _ear, next)| IndexEdge::new(prev, next))
+        .collect();
+    diagonals.into_iter().collect()
+  }
+
+  /// Uniformly sample a random point from the interior of the polygon.
+  ///
+  /// The polygon is triangulated and a triangle is picked with probability
+  /// proportional to its area. The point is then sampled uniformly from the
+  /// triangle.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the polygon has holes.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n^2)$
+  pub fn sample_interior<R>(&self, rng: &mut R) -> Point<f64>
+  where
+    T: PolygonScalar + ToPrimitive,
+    R: Rng + ?Sized,
+  {
+    assert_eq!(
+      self.rings.len(),
+      1,
+      "sample_interior does not support holes"
+    );
+    let trigs: Vec<[Point<f64>; 3]> = self
+      .triangulate()
+      .map(|(a, b, c)| [a.to_f64(), b.to_f64(), c.to_f64()])
+      .collect();
+    let mut total = 0.0;
+    let cumulative: Vec<f64> = trigs
+      .iter()
+      .map(|[a, b, c]| {
+        let ab = b - a;
+        let ac = c - a;
+        total += (ab.0[0] * ac.0[1] - ab.0[1] * ac.0[0]).abs();
+        total
+      })
+      .collect();
+    let target = rng.gen::<f64>() * total;
+    let idx = cumulative
+      .partition_point(|&area| area <= target)
+      .min(trigs.len() - 1);
+    let [a, b, c] = &trigs[idx];
+    let r1 = rng.gen::<f64>().sqrt();
+    let r2 = rng.gen::<f64>();
+    let (u, v, w) = (1.0 - r1, r1 * (1.0 - r2), r1 * r2);
+    Point::new([
+      u * a.array[0] + v * b.array[0] + w * c.array[0],
+      u * a.array[1] + v * b.array[1] + w * c.array[1],
+    ])
+  }
+
+  // Holes are clockwise and therefore subtract from both the area and the
+  // first moment of area.
+  //
+  // # Panics
+  //
+  // May panic for bounded types (i8, isize, etc). Maybe produce inaccurate results for
+  // floating point types (f32, f64).
+  pub fn centroid(&self) -> Point<T>
+  where
+    T: PolygonScalar,
+  {
+    let zero = T::from_constant(0);
+    let mut xs: Vector<T, 2> = Vector([zero.clone(), zero.clone()]);
+    let mut area_2x = zero;
+    for ring in &self.rings {
+      for (i, &src) in ring.iter().enumerate() {
+        let p = self.point(src).as_vec();
+        let q = self.point(ring[(i + 1) % ring.len()]).as_vec();
+        let cross = p.0[0].clone() * q.0[1].clone() - q.0[0].clone() * p.0[1].clone();
+        xs += (p + q) * cross.clone();
+        area_2x += cross;
+      }
+    }
+    let three = T::from_constant(3);
+    Point::from(xs / (three * area_2x))
+  }
+
+  /// Area and centroid computed in floating point. Holes are subtracted.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let p = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([2, 0]),
+  ///   Point::new([2, 2]),
+  ///   Point::new([0, 2]),
+  /// ]).unwrap();
+  /// assert_eq!(p.area_centroid(), (4.0, Point::new([1.0, 1.0])));
+  /// ```
+  pub fn area_centroid(&self) -> (f64, Point<f64, 2>)
+  where
+    T: PolygonScalar + ToPrimitive,
+  {
+    let (origin, [area, cx, cy, ..]) = self.moment_sums();
+    (
+      area,
+      Point::new([origin.array[0] + cx, origin.array[1] + cy]),
+    )
+  }
+
+  /// Second moments of area about the centroid: `[Ixx, Iyy, Ixy]` where
+  /// $I_{xx} = \int y^2 \, dA$, $I_{yy} = \int x^2 \, dA$ and
+  /// $I_{xy} = \int x y \, dA$ with coordinates relative to the centroid.
+  /// Holes are subtracted.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  pub fn second_moments(&self) -> [f64; 3]
+  where
+    T: PolygonScalar + ToPrimitive,
+  {
+    let (_, [area, cx, cy, ixx, iyy, ixy]) = self.moment_sums();
+    [
+      ixx - area * cy * cy,
+      iyy - area * cx * cx,
+      ixy - area * cx * cy,
+    ]
+  }
+
+  // Area, centroid and second moments (about the origin) of every ring in a
+  // single pass over the edges. Coordinates are taken relative to the first
+  // vertex to limit cancellation.
+  fn moment_sums(&self) -> (Point<f64, 2>, [f64; 6])
+  where
+    T: PolygonScalar + ToPrimitive,
+  {
+    let origin = self.point(self.rings[0][0]).to_f64();
+    let [mut area_2x, mut cx, mut cy, mut ixx, mut iyy, mut ixy] = [0.0; 6];
+    for ring in &self.rings {
+      for (i, &src) in ring.iter().enumerate() {
+        let [x0, y0] = (self.point(src).to_f64() - origin).0;
+        let [x1, y1] = (self.point(ring[(i + 1) % ring.len()]).to_f64() - origin).0;
+        let cross = x0 * y1 - x1 * y0;
+        area_2x += cross;
+        cx += (x0 + x1) * cross;
+        cy += (y0 + y1) * cross;
+        ixx += (y0 * y0 + y0 * y1 + y1 * y1) * cross;
+        iyy += (x0 * x0 + x0 * x1 + x1 * x1) * cross;
+        ixy += (x0 * y1 + 2.0 * x0 * y0 + 2.0 * x1 * y1 + x1 * y0) * cross;
+      }
+    }
+    let area = area_2x / 2.0;
+    (
+      origin,
+      [
+        area,
+        cx / (3.0 * area_2x),
+        cy / (3.0 * area_2x),
+        ixx / 12.0,
+        iyy / 12.0,
+        ixy / 24.0,
+      ],
+    )
+  }
+
+  pub fn bounding_box(&self) -> (Point<T>, Point<T>)
+  where
+    T: PolygonScalar,
+  {
+    assert!(!self.rings[0].is_empty());
+    let mut min_x = self.points[self.rings[0][0].usize()].x_coord().clone();
+    let mut max_x = self.points[self.rings[0][0].usize()].x_coord().clone();
+    let mut min_y = self.points[self.rings[0][0].usize()].y_coord().clone();
+    let mut max_y = self.points[self.rings[0][0].usize()].y_coord().clone();
+    for pt in self.iter_boundary() {
+      if pt.point().x_coord() < &min_x {
+        min_x = pt.point().x_coord().clone();
+      }
+      if pt.point().x_coord() > &max_x {
+        max_x = pt.point().x_coord().clone();
+      }
+      if pt.point().y_coord() < &min_y {
+        min_y = pt.point().y_coord().clone();
+      }
+      if pt.point().y_coord() > &max_y {
+        max_y = pt.point().y_coord().clone();
+      }
+    }
+    (Point::new([min_x, min_y]), Point::new([max_x, max_y]))
+  }
+
+  /// Axis-aligned bounding box as a counter-clockwise rectangle, starting at
+  /// the bottom-left corner.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::CoLinearViolation`] if the bounding box has zero width
+  /// or zero height.
+  pub fn bounding_polygon(&self) -> Result<PolygonConvex<T>, Error>
+  where
+    T: PolygonScalar,
+  {
+    let (min, max) = self.bounding_box();
+    let [min_x, min_y] = min.array;
+    let [max_x, max_y] = max.array;
+    if min_x == max_x || min_y == max_y {
+      return Err(Error::CoLinearViolation);
+    }
+    Ok(PolygonConvex::new_unchecked(Polygon::new_unchecked(vec![
+      Point::new([min_x.clone(), min_y.clone()]),
+      Point::new([max_x.clone(), min_y]),
+      Point::new([max_x, max_y.clone()]),
+      Point::new([min_x, max_y]),
+    ])))
+  }
+
+  /// Computes the area of a polygon. If the polygon winds counter-clockwise,
+  /// the area will be a positive number. If the polygon winds clockwise, the area will
+  /// be negative.
+  ///
+  /// # Return type
+  ///
+  /// This function is polymorphic for the same reason as [`signed_area_2x`](Polygon::signed_area_2x).
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  ///
+  /// # Examples
+  /// ```rust
+  /// // The area of this polygon is 1/2 and won't fit in an `i32`
+  /// # use rgeometry::data::*;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let p = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([1, 0]),
+  ///   Point::new([0, 1]),
+  ///  ])?;
+  /// assert_eq!(p.signed_area::<i32>(), 0);
+  /// # Ok(())
+  /// # }
+  /// ```
+  ///
+  /// ```rust
+  /// // The area of this polygon is 1/2 and will fit in a `Ratio<i32>`.
+  /// # use rgeometry::data::*;
+  /// # use num_rational::Ratio;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let p = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([1, 0]),
+  ///   Point::new([0, 1]),
+  ///  ])?;
+  /// assert_eq!(p.signed_area::<Ratio<i32>>(), Ratio::from((1,2)));
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn signed_area<F>(&self) -> F
+  where
+    T: PolygonScalar + Into<F>,
+    F: NumOps<F, F> + Sum + FromPrimitive,
+  {
+    self.signed_area_2x::<F>() / F::from_usize(2).unwrap()
+  }
+
+  /// Compute double the area of a polygon. If the polygon winds counter-clockwise,
+  /// the area will be a positive number. If the polygon winds clockwise, the area will
+  /// be negative.
+  ///
+  /// Why compute double the area? If you are using integer coordinates then the doubled
+  /// area will also be an integer value. Computing the exact requires a division which
+  /// may leave you with a non-integer result.
+  ///
+  /// # Return type
+  ///
+  /// Storing the area of a polygon may require more bits of precision than are used
+  /// for the coordinates. For example, if 32bit integers are used for point coordinates
+  /// then you might need 65 bits to store the area doubled. For this reason, the return
+  /// type is polymorphic and should be selected with care. If you are unsure which type
+  /// is appropriate, use [`BigInt`](num::BigInt) or [`BigRational`](num::BigRational).
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  ///
+  /// # Examples:
+  /// ```rust
+  /// // The area of this polygon is 1/2 and cannot fit in an `i32` variable
+  /// // so lets compute twice the area.
+  /// # use rgeometry::data::*;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let p = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([1, 0]),
+  ///   Point::new([0, 1]),
+  ///  ])?;
+  /// assert_eq!(p.signed_area_2x::<i32>(), 1);
+  /// # Ok(())
+  /// # }
+  /// ```
+  ///
+  /// ```rust
+  /// // The area of a polygon may require more bits of precision than are
+  /// // used for the coordinates.
+  /// # use num::BigInt;
+  /// # use rgeometry::data::*;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let p = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([i32::MAX, 0]),
+  ///   Point::new([0, i32::MAX]),
+  ///  ])?;
+  /// assert_eq!(p.signed_area_2x::<i64>(), 4611686014132420609_i64);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn signed_area_2x<F>(&self) -> F
+  where
+    T: PolygonScalar + Into<F>,
+    F: NumOps<F, F> + Sum,
+  {
+    self
+      .iter_boundary_edges()
+      .map(|edge| {
+        let p = edge.src;
+        let q = edge.dst;
+        p.array[0].clone().into() * q.array[1].clone().into()
+          - q.array[0].clone().into() * p.array[1].clone().into()
+      })
+      .sum()
+  }
+
+  pub fn orientation(&self) -> Orientation
+  where
+    T: PolygonScalar,
+  {
+    match self.iter_boundary().min_by(|a, b| a.point().cmp(b.point())) {
+      None => Orientation::CoLinear,
+      Some(cursor) => cursor.orientation(),
+    }
+  }
+
+  /// Access point of a given vertex.
+  /// # Time complexity
+  /// $O(1)$
+  pub fn point(&self, idx: PointId) -> &Point<T> {
+    &self.points[idx.0]
+  }
+
+  // FIXME: The PointId may not be valid. Return Option<Cursor>.
+  /// Access cursor of a given vertex.
+  /// # Time complexity
+  /// $O(1)$
+  pub fn cursor(&self, idx: PointId) -> Cursor<'_, T> {
+    let ring_id = self.ring_index[idx.0];
+    let position_id = self.position_index[idx.0];
+    Cursor {
+      polygon: self,
+      position: Position {
+        ring_id,
+        position_id,
+        size: self.rings[ring_id.0].len(),
+      },
+    }
   }
 
   pub fn boundary_slice(&self) -> &[PointId] {
@@ -462,6 +1219,46 @@ impl<T> Polygon<T> {
     false
   }
 
+  /// True if the boundaries visit the same points in the same cyclic order,
+  /// regardless of which vertex they start at. With `reflection`, boundaries
+  /// that visit the points in reverse order also match. Points are compared
+  /// exactly and holes are ignored.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n^2)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let a = Polygon::new_unchecked(vec![Point::new([0, 0]), Point::new([1, 0]), Point::new([0, 1])]);
+  /// let b = Polygon::new_unchecked(vec![Point::new([1, 0]), Point::new([0, 1]), Point::new([0, 0])]);
+  /// let c = Polygon::new_unchecked(vec![Point::new([0, 0]), Point::new([0, 1]), Point::new([1, 0])]);
+  /// assert!(a.same_shape(&b, false));
+  /// assert!(!a.same_shape(&c, false));
+  /// assert!(a.same_shape(&c, true));
+  /// ```
+  pub fn same_shape(&self, other: &Polygon<T>, reflection: bool) -> bool
+  where
+    T: PolygonScalar,
+  {
+    let ours: Vec<&Point<T>> = self.iter_boundary().map(|cursor| cursor.point()).collect();
+    let mut theirs: Vec<&Point<T>> = other.iter_boundary().map(|cursor| cursor.point()).collect();
+    let n = ours.len();
+    if n != theirs.len() {
+      return false;
+    }
+    let rotated = |theirs: &[&Point<T>]| {
+      (0..n).any(|offset| (0..n).all(|i| ours[(i + offset) % n] == theirs[i]))
+    };
+    if rotated(&theirs) {
+      return true;
+    }
+    theirs.reverse();
+    reflection && rotated(&theirs)
+  }
+
   pub fn iter_boundary(&self) -> CursorIter<'_, T> {
     let root_cursor = Cursor {
       polygon: self,
@@ -478,133 +1275,704 @@ impl<T> Polygon<T> {
     }
   }
 
+  /// Consecutive `(prev, current, next)` vertices of the boundary, one triple
+  /// for each vertex. The first triple starts at the last vertex and the last
+  /// triple ends at the first vertex.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # use rgeometry::Orientation;
+  /// let arrow = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([4, 2]),
+  ///   Point::new([0, 4]),
+  ///   Point::new([1, 2]),
+  /// ]).unwrap();
+  /// let reflex = arrow
+  ///   .iter_triples()
+  ///   .filter(|(prev, cur, next)| Point::orient(prev, cur, next) == Orientation::ClockWise)
+  ///   .count();
+  /// assert_eq!(reflex, 1);
+  /// ```
+  pub fn iter_triples(
+    &self,
+  ) -> impl Iterator<Item = (Cursor<'_, T>, Cursor<'_, T>, Cursor<'_, T>)> + '_ {
+    self
+      .iter_boundary()
+      .map(|cursor| (cursor.prev(), cursor, cursor.next()))
+  }
+
   pub fn iter_boundary_edges(&self) -> EdgeIter<'_, T> {
     EdgeIter {
       iter: self.iter_boundary(),
     }
   }
 
+  /// Point ids of the boundary edges in boundary order. Each edge starts
+  /// where the previous edge ended and the last edge ends at the start of the
+  /// first edge.
+  pub fn iter_boundary_directed_index_edges(&self) -> impl Iterator<Item = DirectedIndexEdge> + '_ {
+    self.iter_boundary().map(|cursor| DirectedIndexEdge {
+      src: cursor.point_id(),
+      dst: cursor.next().point_id(),
+    })
+  }
+
   #[must_use]
   pub fn map_points<F>(mut self, f: F) -> Polygon<T>
   where
-    T: Clone,
-    F: Fn(Point<T>) -> Point<T>,
+    T: Clone,
+    F: Fn(Point<T>) -> Point<T>,
+  {
+    for pt in self.iter_mut() {
+      *pt = f(pt.clone())
+    }
+    self
+  }
+
+  pub fn iter(&self) -> Iter<'_, T> {
+    Iter {
+      iter: self.points.iter(),
+    }
+  }
+
+  pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+    IterMut {
+      points: self.points.iter_mut(),
+    }
+  }
+
+  pub fn map<U, F>(self, f: F) -> Polygon<U>
+  where
+    T: Clone,
+    U: Clone,
+    F: Fn(T) -> U + Clone,
+  {
+    let pts = self.points.into_iter().map(|p| p.map(f.clone())).collect();
+    Polygon {
+      points: pts,
+      ring_index: self.ring_index,
+      position_index: self.position_index,
+      rings: self.rings,
+    }
+  }
+
+  /// Like [`map`](Self::map) but stops at the first coordinate that can't be
+  /// converted.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # use num::BigRational;
+  /// let p = Polygon::new(vec![
+  ///   Point::new([0.0, 0.0]),
+  ///   Point::new([1.0, 0.0]),
+  ///   Point::new([0.0, 0.5]),
+  /// ]).unwrap();
+  /// let exact = p.try_map(|v| BigRational::from_float(v).ok_or("not finite"));
+  /// assert!(exact.is_ok());
+  /// ```
+  pub fn try_map<U, E, F>(self, f: F) -> Result<Polygon<U>, E>
+  where
+    F: Fn(T) -> Result<U, E>,
+  {
+    let pts = self
+      .points
+      .into_iter()
+      .map(|p| {
+        let [x, y] = p.array;
+        Ok(Point::new([f(x)?, f(y)?]))
+      })
+      .collect::<Result<Vec<Point<U>>, E>>()?;
+    Ok(Polygon {
+      points: pts,
+      ring_index: self.ring_index,
+      position_index: self.position_index,
+      rings: self.rings,
+    })
+  }
+
+  pub fn cast<U>(self) -> Polygon<U>
+  where
+    T: Clone + Into<U>,
+  {
+    let pts = self.points.into_iter().map(|p| p.cast()).collect();
+    Polygon {
+      points: pts,
+      ring_index: self.ring_index,
+      position_index: self.position_index,
+      rings: self.rings,
+    }
+  }
+
+  pub fn float(self) -> Polygon<OrderedFloat<f64>>
+  where
+    T: Clone + Into<f64>,
+  {
+    self.map(|v| OrderedFloat(v.into()))
+  }
+
+  // Reverse all points between p1 and p2, inclusive of p1 and p2.
+  // For example: p1, a, b, c, p2 =>
+  //              p2, c, b, a, p1
+  pub fn vertices_reverse(&mut self, mut pt1: Position, mut pt2: Position) {
+    loop {
+      self.swap_positions(pt1, pt2);
+      pt1.move_next();
+      if pt1 == pt2 {
+        break;
+      }
+      pt2.move_prev();
+      if pt1 == pt2 {
+        break;
+      }
+    }
+  }
+
+  // Move pt2 back until it is right in front of p1.
+  pub fn vertices_join(&mut self, pt1: Position, mut pt2: Position) {
+    while pt2.prev() != pt1 {
+      let prev = pt2.prev();
+      self.swap_positions(prev, pt2);
+      pt2.position_id = prev.position_id;
+    }
+  }
+
+  /// Panics if the edge isn't part of the polygon.
+  pub fn direct(&self, edge: IndexEdge) -> DirectedIndexEdge {
+    let min_cursor = self.cursor(edge.min);
+    let max_cursor = self.cursor(edge.max);
+    if min_cursor.next() == max_cursor {
+      DirectedIndexEdge {
+        src: edge.min,
+        dst: edge.max,
+      }
+    } else if max_cursor.next() == min_cursor {
+      DirectedIndexEdge {
+        src: edge.max,
+        dst: edge.min,
+      }
+    } else {
+      panic!("Edge isn't part of polygon: {:?}", edge)
+    }
+  }
+
+  pub fn ensure_ccw(&mut self) -> Result<(), Error>
+  where
+    T: PolygonScalar,
+  {
+    match self.orientation() {
+      Orientation::CounterClockWise => Ok(()),
+      Orientation::CoLinear => Err(Error::CoLinearViolation),
+      Orientation::ClockWise => {
+        let root_position = Position {
+          ring_id: RingId(0),
+          position_id: PositionId(0),
+          size: self.rings[0].len(),
+        };
+        self.vertices_reverse(root_position, root_position.prev());
+        Ok(())
+      }
+    }
+  }
+
+  /// Cut the polygon in two along the diagonal from `a` to `b`.
+  ///
+  /// The first polygon walks counter-clockwise from `a` to `b`, the second
+  /// from `b` to `a`. Both share the diagonal as an edge.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::InvalidDiagonal`] if `a` and `b` are equal or adjacent,
+  /// or if the diagonal touches the boundary anywhere but its endpoints or
+  /// lies outside the polygon.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the polygon has holes.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let square = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([2, 0]),
+  ///   Point::new([2, 2]),
+  ///   Point::new([0, 2]),
+  /// ])?;
+  /// let ids: Vec<PointId> = square.iter_boundary().map(|c| c.point_id()).collect();
+  /// let (left, right) = square.split_by_diagonal(ids[0], ids[2])?;
+  /// assert_eq!(left.signed_area_2x::<i32>() + right.signed_area_2x::<i32>(), 8);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn split_by_diagonal(&self, a: PointId, b: PointId) -> Result<(Polygon<T>, Polygon<T>), Error>
+  where
+    T: PolygonScalar,
+  {
+    assert_eq!(
+      self.rings.len(),
+      1,
+      "split_by_diagonal does not support holes"
+    );
+    let cursor_a = self.cursor(a);
+    let cursor_b = self.cursor(b);
+    if a == b || cursor_a.next() == cursor_b || cursor_a.prev() == cursor_b {
+      return Err(Error::InvalidDiagonal);
+    }
+    // The diagonal must leave 'a' into the interior.
+    let prev = cursor_a.prev();
+    let next = cursor_a.next();
+    let left_of_prev = Point::orient(&prev, &cursor_a, &cursor_b).is_ccw();
+    let left_of_next = Point::orient(&cursor_a, &next, &cursor_b).is_ccw();
+    let inside = if cursor_a.orientation().is_cw() {
+      left_of_prev || left_of_next
+    } else {
+      left_of_prev && left_of_next
+    };
+    if !inside {
+      return Err(Error::InvalidDiagonal);
+    }
+    // And never touch the boundary on the way to 'b'.
+    let diagonal = LineSegmentView::new(
+      EndPoint::Exclusive(cursor_a.point()),
+      EndPoint::Exclusive(cursor_b.point()),
+    );
+    for edge in self.iter_boundary_edges() {
+      if diagonal.intersect(edge.into()).is_some() {
+        return Err(Error::InvalidDiagonal);
+      }
+    }
+    let chain = |from: Cursor<'_, T>, to: Cursor<'_, T>| -> Vec<Point<T>> {
+      from.to(Included(to)).map(|c| c.point().clone()).collect()
+    };
+    Ok((
+      Polygon::new_unchecked(chain(cursor_a, cursor_b)),
+      Polygon::new_unchecked(chain(cursor_b, cursor_a)),
+    ))
+  }
+
+  /// Subdivide a boundary or hole edge by inserting `p` between its
+  /// endpoints. The new vertex gets the next unused [`PointId`].
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::InvalidEdge`] if the endpoints of `edge` aren't
+  /// adjacent in a ring or if `p` doesn't lie on the edge, and
+  /// [`Error::DuplicatePoints`] if `p` is one of the endpoints.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let mut p = Polygon::new(vec![Point::new([0, 0]), Point::new([2, 0]), Point::new([0, 2])])?;
+  /// let ids: Vec<PointId> = p.iter_boundary().map(|c| c.point_id()).collect();
+  /// let mid = p.insert_vertex_on_edge(IndexEdge::new(ids[0], ids[1]), Point::new([1, 0]))?;
+  /// assert_eq!(p.point(mid), &Point::new([1, 0]));
+  /// assert_eq!(p.iter_boundary().len(), 4);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn insert_vertex_on_edge(&mut self, edge: IndexEdge, p: Point<T, 2>) -> Result<PointId, Error>
+  where
+    T: PolygonScalar,
+  {
+    let cursor_min = self.cursor(edge.min);
+    let cursor_max = self.cursor(edge.max);
+    let src = if cursor_min.next() == cursor_max {
+      cursor_min
+    } else if cursor_max.next() == cursor_min {
+      cursor_max
+    } else {
+      return Err(Error::InvalidEdge);
+    };
+    if &p == cursor_min.point() || &p == cursor_max.point() {
+      return Err(Error::DuplicatePoints);
+    }
+    let segment = LineSegmentView::from(cursor_min.point()..cursor_max.point());
+    if !Point::orient(cursor_min.point(), cursor_max.point(), &p).is_colinear()
+      || !segment.contains(&p)
+    {
+      return Err(Error::InvalidEdge);
+    }
+    let ring_id = src.position.ring_id;
+    let position = src.position.position_id.0 + 1;
+    let pid = PointId(self.points.len());
+    self.points.push(p);
+    self.ring_index.push(ring_id);
+    self.position_index.push(PositionId(position));
+    let ring = &mut self.rings[ring_id.0];
+    ring.insert(position, pid);
+    for (offset, &later) in ring.iter().enumerate().skip(position + 1) {
+      self.position_index[later.0] = PositionId(offset);
+    }
+    Ok(pid)
+  }
+
+  /// True if `other` lies strictly inside `self`: its boundary doesn't touch
+  /// any boundary of `self`, its vertices are inside the outer boundary, and it
+  /// neither lies in nor surrounds a hole of `self`. Holes of `other` are
+  /// ignored.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n m)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let big = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([6, 0]),
+  ///   Point::new([6, 6]),
+  ///   Point::new([0, 6]),
+  /// ])?;
+  /// let small = Polygon::new(vec![Point::new([2, 2]), Point::new([4, 2]), Point::new([3, 4])])?;
+  /// assert!(big.contains_polygon(&small));
+  /// assert!(!small.contains_polygon(&big));
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn contains_polygon(&self, other: &Polygon<T>) -> bool
+  where
+    T: PolygonScalar,
   {
-    for pt in self.iter_mut() {
-      *pt = f(pt.clone())
+    let rings: Vec<Polygon<T>> = self
+      .rings
+      .iter()
+      .map(|ring| Polygon::new_unchecked(ring.iter().map(|&pid| self.point(pid).clone()).collect()))
+      .collect();
+    // No edge of 'other' may touch any ring.
+    for ring in &rings {
+      for edge in ring.iter_boundary_edges() {
+        let edge: LineSegmentView<'_, T> = edge.into();
+        for other_edge in other.iter_boundary_edges() {
+          if edge.intersect(other_edge.into()).is_some() {
+            return false;
+          }
+        }
+      }
     }
-    self
+    // Without touching edges, containment can be decided by a single vertex.
+    let other_vertex = other.point(other.rings[0][0]);
+    let outer = Polygon::new_unchecked(other.iter_boundary().map(|c| c.point().clone()).collect());
+    rings[0].locate(other_vertex) == PointLocation::Inside
+      && rings[1..].iter().all(|hole| {
+        let hole_vertex = hole.point(hole.rings[0][0]);
+        hole.locate(other_vertex) != PointLocation::Inside
+          && outer.locate(hole_vertex) != PointLocation::Inside
+      })
   }
 
-  pub fn iter(&self) -> Iter<'_, T> {
-    Iter {
-      iter: self.points.iter(),
+  /// Add a hole to the polygon.
+  ///
+  /// The hole must be a simple polygon that lies strictly inside the boundary
+  /// and doesn't touch or contain any other hole. It is stored as a clockwise
+  /// ring regardless of the orientation of `hole`.
+  ///
+  /// # Errors
+  ///
+  /// Returns the same errors as [`Polygon::new`] if the hole isn't a simple
+  /// polygon and [`Error::InvalidHole`] if it isn't strictly inside the
+  /// polygon.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n m + m^2)$ where $m$ is the number of vertices in the hole.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # use rgeometry::Error;
+  /// # fn main() -> Result<(), Error> {
+  /// let mut poly = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([6, 0]),
+  ///   Point::new([6, 6]),
+  ///   Point::new([0, 6]),
+  /// ])?;
+  /// poly.add_hole(vec![Point::new([2, 2]), Point::new([4, 2]), Point::new([3, 4])])?;
+  /// assert_eq!(
+  ///   poly.add_hole(vec![Point::new([5, 5]), Point::new([7, 5]), Point::new([6, 7])]),
+  ///   Err(Error::InvalidHole)
+  /// );
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn add_hole(&mut self, hole: Vec<Point<T, 2>>) -> Result<(), Error>
+  where
+    T: PolygonScalar,
+  {
+    let hole = Polygon::new(hole)?;
+    if !self.contains_polygon(&hole) {
+      return Err(Error::InvalidHole);
     }
+    let mut ring: Vec<Point<T>> = hole.iter_boundary().map(|c| c.point().clone()).collect();
+    ring.reverse();
+    self.push_ring_unchecked(ring);
+    Ok(())
   }
 
-  pub fn iter_mut(&mut self) -> IterMut<'_, T> {
-    IterMut {
-      points: self.points.iter_mut(),
+  /// Reverse the direction of every ring.
+  ///
+  /// The boundary becomes clockwise and holes become counter-clockwise. Such
+  /// a polygon fails [`validate`](Polygon::validate) but its boundary can be
+  /// used as a hole in another polygon.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  pub fn reverse_orientation(&mut self) {
+    for ring_id in 0..self.rings.len() {
+      let size = self.rings[ring_id].len();
+      if size < 2 {
+        continue;
+      }
+      let root_position = Position {
+        ring_id: RingId(ring_id),
+        position_id: PositionId(0),
+        size,
+      };
+      self.vertices_reverse(root_position, root_position.prev());
     }
   }
 
-  pub fn map<U, F>(self, f: F) -> Polygon<U>
+  /// Points of every ring, starting with the counter-clockwise boundary
+  /// followed by the clockwise holes. Filling the paths with the even-odd
+  /// rule draws the polygon with its holes cut out.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  pub fn render_paths(&self) -> Vec<Vec<Point<T, 2>>>
   where
     T: Clone,
-    U: Clone,
-    F: Fn(T) -> U + Clone,
   {
-    let pts = self.points.into_iter().map(|p| p.map(f.clone())).collect();
-    Polygon {
-      points: pts,
-      ring_index: self.ring_index,
-      position_index: self.position_index,
-      rings: self.rings,
-    }
+    self
+      .rings
+      .iter()
+      .map(|ring| ring.iter().map(|&pid| self.point(pid).clone()).collect())
+      .collect()
   }
 
-  pub fn cast<U>(self) -> Polygon<U>
+  /// Every ring as a standalone polygon without holes. The boundary comes
+  /// first and is unchanged. Holes follow and are reversed so that they are
+  /// counter-clockwise.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  pub fn rings_as_polygons(&self) -> Vec<Polygon<T>>
   where
-    T: Clone + Into<U>,
+    T: PolygonScalar,
   {
-    let pts = self.points.into_iter().map(|p| p.cast()).collect();
-    Polygon {
-      points: pts,
-      ring_index: self.ring_index,
-      position_index: self.position_index,
-      rings: self.rings,
-    }
+    self
+      .render_paths()
+      .into_iter()
+      .enumerate()
+      .map(|(ring_id, mut ring)| {
+        if ring_id > 0 {
+          ring.reverse();
+        }
+        Polygon::new_unchecked(ring)
+      })
+      .collect()
   }
 
-  pub fn float(self) -> Polygon<OrderedFloat<f64>>
+  /// Reduce the polygon to `k` vertices, preferring the removals that change
+  /// the area the least.
+  ///
+  /// Vertices are removed one at a time (Visvalingam–Whyatt): the vertex that
+  /// forms the smallest triangle with its two neighbours goes first. Removals
+  /// that would make the polygon self-intersecting are skipped. If no vertex
+  /// can be removed safely, the result has more than `k` vertices.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `k` is less than three or if the polygon has holes.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n^3 \log n)$ in the worst case.
+  pub fn simplify_to(&self, k: usize) -> Polygon<f64>
   where
-    T: Clone + Into<f64>,
+    T: PolygonScalar + ToPrimitive,
   {
-    self.map(|v| OrderedFloat(v.into()))
-  }
-
-  // Reverse all points between p1 and p2, inclusive of p1 and p2.
-  // For example: p1, a, b, c, p2 =>
-  //              p2, c, b, a, p1
-  pub fn vertices_reverse(&mut self, mut pt1: Position, mut pt2: Position) {
-    loop {
-      self.swap_positions(pt1, pt2);
-      pt1.move_next();
-      if pt1 == pt2 {
-        break;
-      }
-      pt2.move_prev();
-      if pt1 == pt2 {
-        break;
+    assert!(k >= 3, "a polygon needs at least three vertices");
+    assert_eq!(self.rings.len(), 1, "simplify_to does not support holes");
+    let mut pts: Vec<Point<f64>> = self.iter_boundary().map(|c| c.point().to_f64()).collect();
+    while pts.len() > k {
+      let n = pts.len();
+      let mut candidates: Vec<(f64, usize)> = (0..n)
+        .map(|i| {
+          let triangle =
+            TriangleView::new_unchecked([&pts[(i + n - 1) % n], &pts[i], &pts[(i + 1) % n]]);
+          (triangle.signed_area_2x::<f64>().abs(), i)
+        })
+        .collect();
+      candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+      let removed = candidates.into_iter().find_map(|(_, i)| {
+        let mut remaining = pts.clone();
+        remaining.remove(i);
+        Polygon::new_unchecked(remaining.clone())
+          .validate_weakly()
+          .is_ok()
+          .then_some(remaining)
+      });
+      match removed {
+        Some(remaining) => pts = remaining,
+        None => break,
       }
     }
+    Polygon::new_unchecked(pts)
   }
 
-  // Move pt2 back until it is right in front of p1.
-  pub fn vertices_join(&mut self, pt1: Position, mut pt2: Position) {
-    while pt2.prev() != pt1 {
-      let prev = pt2.prev();
-      self.swap_positions(prev, pt2);
-      pt2.position_id = prev.position_id;
-    }
-  }
-
-  /// Panics if the edge isn't part of the polygon.
-  pub fn direct(&self, edge: IndexEdge) -> DirectedIndexEdge {
-    let min_cursor = self.cursor(edge.min);
-    let max_cursor = self.cursor(edge.max);
-    if min_cursor.next() == max_cursor {
-      DirectedIndexEdge {
-        src: edge.min,
-        dst: edge.max,
-      }
-    } else if max_cursor.next() == min_cursor {
-      DirectedIndexEdge {
-        src: edge.max,
-        dst: edge.min,
+  /// Turning function of the boundary: one `(arclength, angle)` pair per
+  /// edge, starting with the edge leaving vertex 0 at `(0.0, 0.0)`.
+  /// `arclength` is the distance along the boundary to the start of the edge
+  /// as a fraction of the perimeter and `angle` is the total counter-clockwise
+  /// turn from the first edge to this edge. The boundary of a valid polygon
+  /// turns $2\pi$ in total, so the angles are invariant under rotation and
+  /// scaling of the polygon.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let p = Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([2, 0]),
+  ///   Point::new([0, 2]),
+  /// ]).unwrap();
+  /// let turning = p.turning_function();
+  /// assert_eq!(turning[0], (0.0, 0.0));
+  /// assert_eq!(turning[1].1, 3.0 * std::f64::consts::FRAC_PI_4);
+  /// ```
+  pub fn turning_function(&self) -> Vec<(f64, f64)>
+  where
+    T: PolygonScalar + ToPrimitive,
+  {
+    let pts: Vec<Point<f64>> = self.iter_boundary().map(|c| c.point().to_f64()).collect();
+    let n = pts.len();
+    let edges: Vec<Vector<f64, 2>> = (0..n).map(|i| pts[(i + 1) % n] - pts[i]).collect();
+    let lengths: Vec<f64> = edges.iter().map(|e| e.squared_magnitude().sqrt()).collect();
+    let perimeter: f64 = lengths.iter().sum();
+    let (mut arclength, mut angle) = (0.0, 0.0);
+    let mut turning = Vec::with_capacity(n);
+    for i in 0..n {
+      if i > 0 {
+        let [px, py] = edges[i - 1].0;
+        let [x, y] = edges[i].0;
+        arclength += lengths[i - 1];
+        angle += (px * y - py * x).atan2(px * x + py * y);
       }
-    } else {
-      panic!("Edge isn't part of polygon: {:?}", edge)
+      turning.push((arclength / perimeter, angle));
     }
+    turning
   }
 
-  pub fn ensure_ccw(&mut self) -> Result<(), Error>
+  /// Simplify the boundary with the Douglas–Peucker algorithm. Vertices are
+  /// dropped while they are within `sqrt(epsilon_squared)` of the simplified
+  /// boundary. The ring is split at its two farthest-apart vertices and both
+  /// halves are simplified separately.
+  ///
+  /// # Errors
+  ///
+  /// Returns the errors of [`validate_weakly`](Self::validate_weakly) if the
+  /// simplified polygon isn't simple, e.g. [`Error::SelfIntersections`] if
+  /// the simplification crosses itself or [`Error::InsufficientVertices`] if
+  /// fewer than three vertices remain.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the polygon has holes.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n^2)$
+  pub fn simplify_dp(&self, epsilon_squared: T) -> Result<Polygon<f64>, Error>
   where
-    T: PolygonScalar,
+    T: PolygonScalar + ToPrimitive,
   {
-    match self.orientation() {
-      Orientation::CounterClockWise => Ok(()),
-      Orientation::CoLinear => Err(Error::CoLinearViolation),
-      Orientation::ClockWise => {
-        let root_position = Position {
-          ring_id: RingId(0),
-          position_id: PositionId(0),
-          size: self.rings[0].len(),
-        };
-        self.vertices_reverse(root_position, root_position.prev());
-        Ok(())
+    assert_eq!(self.rings.len(), 1, "simplify_dp does not support holes");
+    let epsilon_squared = epsilon_squared.to_f64().unwrap();
+    let pts: Vec<Point<f64>> = self.iter_boundary().map(|c| c.point().to_f64()).collect();
+    let n = pts.len();
+    let distance_squared = |a: &Point<f64>, b: &Point<f64>| (*b - *a).squared_magnitude();
+    let (start, end) = (0..n)
+      .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+      .max_by(|&(a, b), &(c, d)| {
+        distance_squared(&pts[a], &pts[b]).total_cmp(&distance_squared(&pts[c], &pts[d]))
+      })
+      .unwrap_or((0, 0));
+    let mut keep = vec![false; n];
+    keep[start] = true;
+    keep[end] = true;
+    // Chains are given as (first, last) positions along the ring.
+    let mut chains = vec![(start, end), (end, start + n)];
+    while let Some((first, last)) = chains.pop() {
+      let (a, b) = (&pts[first % n], &pts[last % n]);
+      let segment = LineSegmentView::new(EndPoint::Inclusive(a), EndPoint::Inclusive(b));
+      let farthest = (first + 1..last)
+        .map(|i| {
+          (
+            i,
+            distance_squared(&segment.closest_point(&pts[i % n]), &pts[i % n]),
+          )
+        })
+        .max_by(|x, y| x.1.total_cmp(&y.1));
+      if let Some((i, dist)) = farthest {
+        if dist > epsilon_squared {
+          keep[i % n] = true;
+          chains.push((first, i));
+          chains.push((i, last));
+        }
       }
     }
+    let simple = Polygon::new_unchecked(
+      pts
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(pt, kept)| kept.then_some(pt))
+        .collect(),
+    );
+    simple.validate_weakly()?;
+    Ok(simple)
+  }
+
+  // Append a ring without checking that it is valid. Holes must be clockwise.
+  pub(crate) fn push_ring_unchecked(&mut self, ring: Vec<Point<T>>) {
+    let ring_id = RingId(self.rings.len());
+    let offset = self.points.len();
+    self
+      .ring_index
+      .extend(std::iter::repeat(ring_id).take(ring.len()));
+    self.position_index.extend((0..ring.len()).map(PositionId));
+    self
+      .rings
+      .push((offset..offset + ring.len()).map(PointId).collect());
+    self.points.extend(ring);
   }
 
   fn position_to_point_id(&self, position: Position) -> PointId {
@@ -622,12 +1990,91 @@ impl<T> Polygon<T> {
     self.position_index.swap(pa_point_id.0, pb_point_id.0);
   }
 
+  /// Copy of the polygon moved by `v`.
+  pub fn translated(&self, v: &Vector<T, 2>) -> Polygon<T>
+  where
+    T: PolygonScalar,
+  {
+    self.clone().map_points(|pt| pt + v)
+  }
+
+  /// Copy of the polygon scaled by `factor` around `center`. Uniform scaling
+  /// preserves orientation, even for negative factors.
+  ///
+  /// # Panics
+  ///
+  /// May panic for bounded types (i8, isize, etc) if a coordinate overflows.
+  pub fn scaled_about(&self, center: &Point<T, 2>, factor: T) -> Polygon<T>
+  where
+    T: PolygonScalar,
+  {
+    self
+      .clone()
+      .map_points(|pt| center + &((&pt - center) * factor.clone()))
+  }
+
   pub fn is_monotone(&self, direction: &Vector<T, 2>) -> bool
   where
     T: PolygonScalar,
   {
     crate::algorithms::polygonization::monotone::is_monotone(self, direction)
   }
+
+  /// Find a direction with respect to which the polygon is monotone.
+  ///
+  /// The polygon can only change from monotone to non-monotone at
+  /// directions perpendicular to one of its edges. One direction between each
+  /// pair of such critical directions is checked with
+  /// [`is_monotone`](Self::is_monotone). Returns `None` if no direction works.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the polygon has holes.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n^2)$
+  pub fn monotone_direction(&self) -> Option<Vector<f64, 2>>
+  where
+    T: PolygonScalar + ToPrimitive,
+  {
+    use std::f64::consts::PI;
+    assert_eq!(
+      self.rings.len(),
+      1,
+      "monotone_direction does not support holes"
+    );
+    let poly: Polygon<f64> =
+      Polygon::new_unchecked(self.iter_boundary().map(|c| c.point().to_f64()).collect());
+    // Directions perpendicular to the edges, modulo pi.
+    let mut critical: Vec<f64> = poly
+      .iter_boundary_edges()
+      .filter(|edge| edge.src != edge.dst)
+      .map(|edge| {
+        let [dx, dy] = (edge.dst - edge.src).0;
+        dx.atan2(-dy).rem_euclid(PI)
+      })
+      .collect();
+    critical.sort_by(f64::total_cmp);
+    critical.dedup();
+    let first = *critical.first()?;
+    critical.push(first + PI);
+    critical
+      .windows(2)
+      .map(|pair| (pair[0] + pair[1]) / 2.0)
+      .map(|angle| Vector([angle.cos(), angle.sin()]))
+      .find(|direction| poly.is_monotone(direction))
+  }
+}
+
+impl Polygon<f64> {
+  /// Copy of the polygon rotated counter-clockwise by `angle` radians around
+  /// `center`.
+  pub fn rotated_about(&self, center: &Point<f64, 2>, angle: f64) -> Polygon<f64> {
+    self
+      .clone()
+      .map_points(|pt| center + &(&pt - center).rotate(angle))
+  }
 }
 
 impl Polygon<OrderedFloat<f64>> {
@@ -641,9 +2088,11 @@ impl Polygon<OrderedFloat<f64>> {
     let height = max_y - min_y;
     let ratio = std::cmp::max(width, height);
     let centroid = self.centroid();
-    let t = Transform::translate(-Vector::from(centroid));
-    let s = Transform::uniform_scale(ratio.recip());
-    s * t * self
+    let t = TransformBuilder::new()
+      .translate(-Vector::from(centroid))
+      .uniform_scale(ratio.recip())
+      .build();
+    t * self
   }
 }
 
@@ -788,32 +2237,200 @@ impl Position {
     self
   }
 
-  pub fn move_next(&mut self) {
-    if self.position_id.0 == self.size - 1 {
-      self.position_id.0 = 0;
-    } else {
-      self.position_id.0 += 1;
+  pub fn move_next(&mut self) {
+    if self.position_id.0 == self.size - 1 {
+      self.position_id.0 = 0;
+    } else {
+      self.position_id.0 += 1;
+    }
+  }
+
+  pub fn move_prev(&mut self) {
+    if self.position_id.0 == 0 {
+      self.position_id.0 = self.size - 1;
+    } else {
+      self.position_id.0 -= 1;
+    }
+  }
+}
+
+#[cfg(test)]
+pub mod tests {
+  use super::*;
+
+  use crate::testing::*;
+  use num::BigRational;
+  use ordered_float::NotNan;
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+  use proptest::proptest as proptest_block;
+  use rand::rngs::SmallRng;
+  use rand::SeedableRng;
+
+  #[test]
+  fn nearest_boundary_point_square() {
+    let square = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([4, 0]),
+      Point::new([4, 4]),
+      Point::new([0, 4]),
+    ])
+    .unwrap();
+    // Just outside the top edge.
+    let (edge, snapped) = square.nearest_boundary_point(&Point::new([1, 5]));
+    assert_eq!(edge, IndexEdge::new(PointId(2), PointId(3)));
+    assert_eq!(snapped, Point::new([1.0, 4.0]));
+    // Beyond a corner, both edges reach the vertex.
+    let (edge, snapped) = square.nearest_boundary_point(&Point::new([6, -1]));
+    assert_eq!(edge, IndexEdge::new(PointId(0), PointId(1)));
+    assert_eq!(snapped, Point::new([4.0, 0.0]));
+  }
+
+  #[test]
+  fn locate_sos_square() {
+    let square = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([4, 0]),
+      Point::new([4, 4]),
+      Point::new([0, 4]),
+    ])
+    .unwrap();
+    // Bottom and left edges are inside, top and right edges are outside.
+    assert!(square.locate_sos(&Point::new([2, 0])));
+    assert!(square.locate_sos(&Point::new([0, 2])));
+    assert!(!square.locate_sos(&Point::new([2, 4])));
+    assert!(!square.locate_sos(&Point::new([4, 2])));
+    // Only the bottom-left vertex is inside.
+    assert!(square.locate_sos(&Point::new([0, 0])));
+    assert!(!square.locate_sos(&Point::new([4, 0])));
+    assert!(!square.locate_sos(&Point::new([4, 4])));
+    assert!(!square.locate_sos(&Point::new([0, 4])));
+    assert!(square.locate_sos(&Point::new([2, 2])));
+    assert!(!square.locate_sos(&Point::new([5, 2])));
+  }
+
+  #[test]
+  fn iter_triples_triangle() {
+    let poly = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([1, 0]),
+      Point::new([0, 1]),
+    ])
+    .unwrap();
+    let triples: Vec<[PointId; 3]> = poly
+      .iter_triples()
+      .map(|(a, b, c)| [a.point_id(), b.point_id(), c.point_id()])
+      .collect();
+    let ids: Vec<PointId> = poly.iter_boundary().map(|c| c.point_id()).collect();
+    assert_eq!(
+      triples,
+      vec![
+        [ids[2], ids[0], ids[1]],
+        [ids[0], ids[1], ids[2]],
+        [ids[1], ids[2], ids[0]],
+      ]
+    );
+    let mut sorted_ids = ids;
+    sorted_ids.sort();
+    for mut triple in triples {
+      triple.sort();
+      assert_eq!(triple.to_vec(), sorted_ids);
+    }
+  }
+
+  #[test]
+  fn locate_with_tolerance_square() {
+    let poly = square(0, 0, 10);
+    let eps = 0.01;
+    let locate = |x: f64, y: f64| poly.locate_with_tolerance(&Point::new([x, y]), eps);
+    assert_eq!(locate(10.0 + eps / 2.0, 5.0), PointLocation::OnBoundary);
+    assert_eq!(locate(10.0 + 2.0 * eps, 5.0), PointLocation::Outside);
+    assert_eq!(locate(10.0 - eps / 2.0, 5.0), PointLocation::OnBoundary);
+    assert_eq!(locate(10.0 - 2.0 * eps, 5.0), PointLocation::Inside);
+    // Near a corner, diagonally outside.
+    assert_eq!(locate(-eps / 2.0, -eps / 2.0), PointLocation::OnBoundary);
+    assert_eq!(locate(-eps, -eps), PointLocation::Outside);
+  }
+
+  #[test]
+  fn is_simple_fast_hole() {
+    let mut poly = square(0, 0, 10);
+    poly.add_hole(square_points(4, 4, 2)).unwrap();
+    assert!(poly.is_simple_fast());
+  }
+
+  #[test]
+  fn locate_with_tolerance_hole() {
+    let mut poly = square(0, 0, 10);
+    poly.add_hole(square_points(4, 4, 2)).unwrap();
+    let eps = 0.01;
+    let locate = |x: f64, y: f64| poly.locate_with_tolerance(&Point::new([x, y]), eps);
+    assert_eq!(locate(5.0, 5.0), PointLocation::Outside);
+    assert_eq!(locate(4.0 - eps / 2.0, 5.0), PointLocation::OnBoundary);
+    assert_eq!(locate(4.0 - 2.0 * eps, 5.0), PointLocation::Inside);
+    assert_eq!(locate(1.0, 5.0), PointLocation::Inside);
+  }
+
+  #[test]
+  fn same_shape_square() {
+    let sq = square_points(0, 0, 2);
+    let poly = Polygon::new(sq.clone()).unwrap();
+    for k in 0..4 {
+      let mut rotated = sq.clone();
+      rotated.rotate_left(k);
+      assert!(poly.same_shape(&Polygon::new(rotated).unwrap(), false));
     }
+    let mut mirrored = sq;
+    mirrored.reverse();
+    let mirrored = Polygon::new_unchecked(mirrored);
+    assert!(!poly.same_shape(&mirrored, false));
+    assert!(poly.same_shape(&mirrored, true));
+    assert!(!poly.same_shape(&square(0, 0, 3), true));
   }
 
-  pub fn move_prev(&mut self) {
-    if self.position_id.0 == 0 {
-      self.position_id.0 = self.size - 1;
-    } else {
-      self.position_id.0 -= 1;
-    }
+  #[test]
+  fn new_oriented_square() {
+    let ccw = square_points(0, 0, 2);
+    let poly = Polygon::new_oriented(ccw.clone(), Orientation::CounterClockWise).unwrap();
+    assert_eq!(poly.points, ccw);
+    let mut cw = ccw.clone();
+    cw.reverse();
+    assert_eq!(
+      Polygon::new_oriented(cw.clone(), Orientation::CounterClockWise).err(),
+      Some(Error::ClockWiseViolation)
+    );
+    assert_eq!(
+      Polygon::new_oriented(ccw, Orientation::ClockWise).err(),
+      Some(Error::ClockWiseViolation)
+    );
+    let poly = Polygon::new_oriented(cw, Orientation::ClockWise).unwrap();
+    assert_eq!(poly.orientation(), Orientation::CounterClockWise);
   }
-}
-
-#[cfg(test)]
-pub mod tests {
-  use super::*;
 
-  use crate::testing::*;
-  use ordered_float::NotNan;
-  use proptest::collection::vec;
-  use proptest::prelude::*;
-  use proptest::proptest as proptest_block;
+  #[test]
+  fn try_map_rational() {
+    let pts = vec![
+      Point::new([0.0, 0.0]),
+      Point::new([1.5, 0.0]),
+      Point::new([0.0, 0.25]),
+    ];
+    let poly = Polygon::new(pts.clone()).unwrap();
+    let exact = poly
+      .try_map(|v| BigRational::from_float(v).ok_or(v))
+      .unwrap();
+    assert_eq!(
+      exact.point(PointId(1)),
+      &Point::new([BigRational::new(3.into(), 2.into()), BigRational::zero()])
+    );
+    assert_eq!(exact.validate().err(), None);
+
+    let mut nan = Polygon::new_unchecked(pts);
+    nan.points[2] = Point::new([f64::NAN, 0.25]);
+    assert!(nan
+      .try_map(|v| BigRational::from_float(v).ok_or(v))
+      .unwrap_err()
+      .is_nan());
+  }
 
   proptest_block! {
     #[test]
@@ -834,6 +2451,68 @@ pub mod tests {
       let _ = poly.clone();
     }
 
+    #[test]
+    fn simplify_valid_prop(poly: Polygon<i8>, k in 3_usize..10) {
+      prop_assert_eq!(poly.simplify_to(k).validate_weakly().err(), None);
+    }
+
+    #[test]
+    fn self_intersections_prop(pts: Vec<Point<i8>>) {
+      let mut seen = std::collections::BTreeSet::new();
+      let pts: Vec<Point<i8>> = pts.into_iter().filter(|pt| seen.insert(*pt)).collect();
+      let poly = Polygon::new_unchecked(pts);
+      if poly.iter().count() >= 3 && poly.orientation() == Orientation::CounterClockWise {
+        // Touching edges make a polygon invalid without being reported.
+        if !poly.self_intersections().is_empty() {
+          prop_assert_eq!(poly.validate().err(), Some(Error::SelfIntersections));
+        }
+        if poly.validate().is_ok() {
+          prop_assert!(poly.self_intersections().is_empty());
+        }
+      }
+    }
+
+    #[test]
+    fn is_simple_fast_prop(poly: Polygon<i8>, pts in vec(any::<Point<i8>>(), 3..30), k in 0..100_usize) {
+      // Random points are rarely simple, so also move one vertex of a simple
+      // polygon to a random point.
+      let poly: Polygon<i32> = poly.cast();
+      let mut moved: Vec<Point<i32>> = poly.iter().cloned().collect();
+      let n = moved.len();
+      moved[k % n] = pts[k % pts.len()].cast();
+      let random = Polygon::new_unchecked(pts.iter().map(Point::cast).collect::<Vec<Point<i32>>>());
+      for poly in [poly, Polygon::new_unchecked(moved), random] {
+        let edges: Vec<DirectedEdge<'_, i32, 2>> = poly.iter_boundary_edges().collect();
+        let mut isects = crate::algorithms::segment_intersections(&edges);
+        prop_assert_eq!(poly.is_simple_fast(), isects.next().is_none());
+      }
+    }
+
+    #[test]
+    fn locate_sos_prop(poly: Polygon<i8>, pt: Point<i8>) {
+      match poly.locate(&pt) {
+        PointLocation::Inside => prop_assert!(poly.locate_sos(&pt)),
+        PointLocation::Outside => prop_assert!(!poly.locate_sos(&pt)),
+        PointLocation::OnBoundary => {}
+      }
+    }
+
+    #[test]
+    fn locate_sos_vertex_prop(poly: Polygon<i8>) {
+      // After scaling up, a small step right and a much smaller step up
+      // from a vertex stays clear of every edge that doesn't touch it.
+      let scale = 1 << 20;
+      let scaled: Polygon<i64> = poly.clone().cast().map(|v: i64| v * scale);
+      for pt in poly.iter() {
+        let [x, y] = pt.cast::<i64>().array;
+        let nudged = Point::new([x * scale + 1000, y * scale + 1]);
+        prop_assert_eq!(
+          poly.locate_sos(pt),
+          scaled.locate(&nudged) == PointLocation::Inside
+        );
+      }
+    }
+
     #[test]
     fn fuzz_validate(pts: Vec<Point<i8>>) {
       // make sure there's no input that can cause a panic. Err is okay, panic is not.
@@ -851,6 +2530,21 @@ pub mod tests {
       poly.centroid();
     }
 
+    #[test]
+    fn turning_function_start_invariant(poly: Polygon<i8>, k in 0..100_usize) {
+      let mut pts: Vec<Point<i8>> = poly.iter_boundary().map(|c| *c.point()).collect();
+      let k = k % pts.len();
+      pts.rotate_left(k);
+      let rotated = Polygon::new(pts).unwrap();
+      let mut steps = turning_steps(&poly.turning_function());
+      steps.rotate_left(k);
+      let rotated_steps = turning_steps(&rotated.turning_function());
+      for ((s1, a1), (s2, a2)) in steps.into_iter().zip(rotated_steps) {
+        prop_assert!((s1 - s2).abs() < 1e-9);
+        prop_assert!((a1 - a2).abs() < 1e-9);
+      }
+    }
+
     #[test]
     fn signed_area_non_negative_prop(poly in polygon_nn()) {
       prop_assert!( poly.signed_area::<NotNan<f64>>() >= NotNan::zero())
@@ -876,6 +2570,53 @@ pub mod tests {
       )
     }
 
+    #[test]
+    fn sample_interior_prop(poly: Polygon<i8>, seed: u64) {
+      let rng = &mut SmallRng::seed_from_u64(seed);
+      let float = poly.clone().map(|v| v as f64);
+      for _ in 0..10 {
+        let pt = poly.sample_interior(rng);
+        prop_assert_eq!(float.locate(&pt), PointLocation::Inside);
+      }
+    }
+
+    #[test]
+    fn winding_number_prop(poly: Polygon<i8>, origin: Point<i8>) {
+      let expected = match poly.locate(&origin) {
+        PointLocation::Inside => 1,
+        PointLocation::Outside => 0,
+        PointLocation::OnBoundary => return Ok(()),
+      };
+      prop_assert_eq!(poly.winding_number(&origin), expected);
+    }
+
+    #[test]
+    fn centroid_single_ring_prop(poly: Polygon<i8>) {
+      // Boundary-only formula.
+      let poly = poly.cast::<i64>();
+      let xs: Vector<i64, 2> = poly
+        .iter_boundary_edges()
+        .map(|edge| {
+          let p = edge.src.as_vec();
+          let q = edge.dst.as_vec();
+          (p + q) * (p.0[0] * q.0[1] - q.0[0] * p.0[1])
+        })
+        .sum();
+      let expected = Point::from(xs / (3 * poly.signed_area_2x::<i64>()));
+      prop_assert_eq!(poly.centroid(), expected);
+    }
+
+    #[test]
+    fn reverse_orientation_prop(poly: Polygon<i8>) {
+      let mut reversed = poly.clone();
+      reversed.reverse_orientation();
+      prop_assert_eq!(reversed.orientation(), Orientation::ClockWise);
+      prop_assert_eq!(reversed.validate().err(), Some(Error::ClockWiseViolation));
+      reversed.reverse_orientation();
+      prop_assert_eq!(reversed.orientation(), Orientation::CounterClockWise);
+      prop_assert!(reversed.equals(&poly));
+    }
+
     #[test]
     fn equals_identity_prop(poly: Polygon<i8>, offset: usize) {
       let points: Vec<Point<i8>> = poly.iter_boundary().map(|cursor| cursor.point()).cloned().collect();
@@ -899,6 +2640,713 @@ pub mod tests {
   //   // prop_assert!(width == OrderedFloat(1.0) || height == OrderedFloat(1.0));
   // }
 
+  #[test]
+  fn directed_index_edges_closed_ring() {
+    let mut poly = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([1, 0]),
+      Point::new([1, 1]),
+      Point::new([0, 1]),
+    ])
+    .unwrap();
+    let root = poly.iter_boundary().next().unwrap().position;
+    poly.vertices_reverse(root, root.prev());
+    let ids: Vec<PointId> = poly.iter_boundary().map(|c| c.point_id()).collect();
+    let edges: Vec<DirectedIndexEdge> = poly.iter_boundary_directed_index_edges().collect();
+    assert_eq!(edges.len(), 4);
+    for (i, edge) in edges.iter().enumerate() {
+      assert_eq!(edge.src, ids[i]);
+      assert_eq!(edge.dst, edges[(i + 1) % 4].src);
+      assert_eq!(poly.direct(IndexEdge::from(*edge)), *edge);
+    }
+  }
+
+  #[test]
+  fn turning_function_square() {
+    let poly = square(0, 0, 3);
+    let expected = [(0.0, 0.0), (0.25, 1.0), (0.5, 2.0), (0.75, 3.0)];
+    let turning = poly.turning_function();
+    assert_eq!(turning.len(), 4);
+    for ((s, angle), (expected_s, steps)) in turning.into_iter().zip(expected) {
+      assert_eq!(s, expected_s);
+      assert!((angle - steps * std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    }
+  }
+
+  // Arclength and turn of each edge, measured from the previous edge.
+  fn turning_steps(turning: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let n = turning.len();
+    let total = std::f64::consts::TAU;
+    (0..n)
+      .map(|i| {
+        let (s, angle) = turning[i];
+        let (next_s, _) = turning.get(i + 1).copied().unwrap_or((1.0, total));
+        let prev_angle = if i == 0 {
+          turning[n - 1].1 - total
+        } else {
+          turning[i - 1].1
+        };
+        (next_s - s, angle - prev_angle)
+      })
+      .collect()
+  }
+
+  #[test]
+  fn moments_centered_unit_square() {
+    let poly = Polygon::new(vec![
+      Point::new([-0.5, -0.5]),
+      Point::new([0.5, -0.5]),
+      Point::new([0.5, 0.5]),
+      Point::new([-0.5, 0.5]),
+    ])
+    .unwrap();
+    assert_eq!(poly.area_centroid(), (1.0, Point::new([0.0, 0.0])));
+    let [ixx, iyy, ixy] = poly.second_moments();
+    assert!((ixx - 1.0 / 12.0).abs() < 1e-12);
+    assert!((iyy - 1.0 / 12.0).abs() < 1e-12);
+    assert!(ixy.abs() < 1e-12);
+  }
+
+  #[test]
+  fn moments_with_hole() {
+    let mut poly = square(10, 20, 4);
+    poly.add_hole(square_points(11, 21, 2)).unwrap();
+    assert_eq!(poly.area_centroid(), (12.0, Point::new([12.0, 22.0])));
+    // (4^4 - 2^4) / 12
+    assert_eq!(poly.second_moments(), [20.0, 20.0, 0.0]);
+  }
+
+  #[test]
+  fn triangulate_indices_pentagon() {
+    let poly = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([4, 0]),
+      Point::new([5, 3]),
+      Point::new([2, 5]),
+      Point::new([-1, 3]),
+    ])
+    .unwrap();
+    let trigs = poly.triangulate_indices();
+    assert_eq!(trigs.len(), 3);
+    let mut area = 0;
+    for [a, b, c] in trigs {
+      let trig = TriangleView::new_unchecked([poly.point(a), poly.point(b), poly.point(c)]);
+      assert!(trig.signed_area_2x::<i32>() > 0);
+      area += trig.signed_area_2x::<i32>();
+    }
+    assert_eq!(area, poly.signed_area_2x::<i32>());
+  }
+
+  #[test]
+  fn triangulation_diagonals_pentagon() {
+    let poly = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([4, 0]),
+      Point::new([5, 3]),
+      Point::new([2, 5]),
+      Point::new([-1, 3]),
+    ])
+    .unwrap();
+    let diagonals = poly.triangulation_diagonals();
+    assert_eq!(diagonals.len(), 2);
+    let float: Polygon<f64> = poly.clone().cast();
+    for edge in diagonals {
+      let segment = LineSegmentView::new(
+        EndPoint::Inclusive(poly.point(edge.min)),
+        EndPoint::Inclusive(poly.point(edge.max)),
+      );
+      assert_eq!(float.locate(&segment.midpoint()), PointLocation::Inside);
+    }
+  }
+
+  #[test]
+  fn edge_lengths_right_triangle() {
+    let poly: Polygon<i32> = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([3, 0]),
+      Point::new([3, 4]),
+    ])
+    .unwrap();
+    assert_eq!(poly.edge_lengths_squared(), vec![9, 16, 25]);
+    assert_eq!(
+      poly.longest_edge(),
+      (IndexEdge::new(PointId(2), PointId(0)), 25)
+    );
+    assert_eq!(
+      poly.shortest_edge(),
+      (IndexEdge::new(PointId(0), PointId(1)), 9)
+    );
+  }
+
+  #[test]
+  fn winding_number_triangle() {
+    let mut poly: Polygon<i32> = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([10, 0]),
+      Point::new([0, 10]),
+    ])
+    .unwrap();
+    assert_eq!(poly.winding_number(&Point::new([2, 2])), 1);
+    assert_eq!(poly.winding_number(&Point::new([8, 8])), 0);
+    assert_eq!(poly.winding_number(&Point::new([-1, 2])), 0);
+    let root = poly.iter_boundary().next().unwrap().position;
+    poly.vertices_reverse(root, root.prev());
+    assert_eq!(poly.winding_number(&Point::new([2, 2])), -1);
+  }
+
+  #[test]
+  fn winding_number_figure_eight() {
+    // The left loop is counter-clockwise and the right loop is clockwise.
+    let poly: Polygon<i32> = Polygon::new_unchecked(vec![
+      Point::new([0, 0]),
+      Point::new([-4, -2]),
+      Point::new([-4, 2]),
+      Point::new([4, -2]),
+      Point::new([4, 2]),
+    ]);
+    assert_eq!(poly.winding_number(&Point::new([-3, 0])), -1);
+    assert_eq!(poly.winding_number(&Point::new([3, 0])), 1);
+    assert_eq!(poly.winding_number(&Point::new([0, 5])), 0);
+  }
+
+  #[test]
+  fn winding_number_double() {
+    // Two overlapping squares traversed as a single ring.
+    let poly: Polygon<i32> = Polygon::new_unchecked(vec![
+      Point::new([0, 0]),
+      Point::new([4, 0]),
+      Point::new([4, 4]),
+      Point::new([0, 4]),
+      Point::new([2, 0]),
+      Point::new([6, 0]),
+      Point::new([6, 4]),
+      Point::new([2, 4]),
+    ]);
+    assert_eq!(poly.winding_number(&Point::new([3, 2])), 2);
+    assert_eq!(poly.winding_number(&Point::new([5, 2])), 1);
+    assert_eq!(poly.winding_number(&Point::new([7, 2])), 0);
+  }
+
+  #[test]
+  fn centroid_with_hole() {
+    let mut poly = square(0, 0, 6);
+    let mut hole = square_points(2, 2, 2);
+    hole.reverse();
+    poly.push_ring_unchecked(hole);
+    assert_eq!(poly.centroid(), Point::new([3, 3]));
+
+    // An off-center hole pushes the centroid the other way.
+    let mut poly = square(0, 0, 6);
+    let mut hole = square_points(0, 0, 3);
+    hole.reverse();
+    poly.push_ring_unchecked(hole);
+    let centroid = poly.map(|v| v as f64).centroid();
+    // Area 36 with centroid (3,3) minus area 9 with centroid (1.5,1.5).
+    let expected = (36.0 * 3.0 - 9.0 * 1.5) / 27.0;
+    assert_eq!(centroid, Point::new([expected, expected]));
+  }
+
+  #[test]
+  fn simplify_circle() {
+    let circle: Vec<Point<i32>> = (0..100_i32)
+      .map(|i| {
+        let angle = std::f64::consts::TAU * i as f64 / 100.0;
+        Point::new([
+          (1000.0 * angle.cos()).round() as i32,
+          (1000.0 * angle.sin()).round() as i32,
+        ])
+      })
+      .collect();
+    let circle = Polygon::new(circle).unwrap();
+    let simple = circle.simplify_to(8);
+    assert_eq!(simple.iter().count(), 8);
+    assert_eq!(simple.validate().err(), None);
+    // A regular octagon covers 2*sqrt(2)/pi (~90%) of its circumscribed circle.
+    let ratio = simple.signed_area::<f64>() / circle.signed_area::<f64>();
+    assert!(ratio > 0.88 && ratio < 1.0, "area ratio: {}", ratio);
+  }
+
+  #[test]
+  fn simplify_noop() {
+    let poly = square(0, 0, 10);
+    assert!(poly.simplify_to(10).equals(&poly.clone().cast()));
+    assert_eq!(poly.simplify_to(3).iter().count(), 3);
+  }
+
+  #[test]
+  fn simplify_dp_noisy_square() {
+    let mut pts = Vec::new();
+    let noise = |i: i32| if i % 2 == 0 { 0.1 } else { -0.1 };
+    for i in 0..10 {
+      pts.push(Point::new([i as f64, noise(i)]));
+    }
+    for i in 0..10 {
+      pts.push(Point::new([10.0 + noise(i), i as f64]));
+    }
+    for i in 0..10 {
+      pts.push(Point::new([(10 - i) as f64, 10.0 + noise(i)]));
+    }
+    for i in 0..10 {
+      pts.push(Point::new([noise(i), (10 - i) as f64]));
+    }
+    let noisy = Polygon::new(pts).unwrap();
+    let simple = noisy.simplify_dp(0.25).unwrap();
+    let expected = Polygon::new(vec![
+      Point::new([0.0, 0.1]),
+      Point::new([10.1, 0.0]),
+      Point::new([10.0, 10.1]),
+      Point::new([0.1, 10.0]),
+    ])
+    .unwrap();
+    assert!(simple.equals(&expected));
+  }
+
+  #[test]
+  fn simplify_dp_rejected() {
+    // Dropping the bottom vertex moves the bottom edge above the notch.
+    let poly = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([10, -2]),
+      Point::new([20, 0]),
+      Point::new([14, 6]),
+      Point::new([10, -1]),
+      Point::new([6, 6]),
+    ])
+    .unwrap();
+    assert_eq!(poly.simplify_dp(5).err(), Some(Error::SelfIntersections));
+    assert_eq!(poly.simplify_dp(1).unwrap().iter().count(), 6);
+    assert_eq!(
+      poly.simplify_dp(100).err(),
+      Some(Error::InsufficientVertices)
+    );
+  }
+
+  #[test]
+  fn self_intersections_simple_and_bowtie() {
+    let poly = square(0, 0, 4);
+    assert_eq!(poly.self_intersections(), vec![]);
+    let bowtie = Polygon::new_unchecked(vec![
+      Point::new([0, 0]),
+      Point::new([2, 2]),
+      Point::new([2, 0]),
+      Point::new([0, 2]),
+    ]);
+    let ids: Vec<PointId> = bowtie.iter_boundary().map(|c| c.point_id()).collect();
+    assert_eq!(
+      bowtie.self_intersections(),
+      vec![(
+        IndexEdge::new(ids[0], ids[1]),
+        IndexEdge::new(ids[2], ids[3])
+      )]
+    );
+  }
+
+  #[test]
+  fn self_intersections_touching_and_holes() {
+    // The vertex (3, 0) lies on the bottom edge without crossing it.
+    let touching = Polygon::new_unchecked(vec![
+      Point::new([0, 0]),
+      Point::new([6, 0]),
+      Point::new([6, 4]),
+      Point::new([3, 0]),
+      Point::new([0, 4]),
+    ]);
+    assert!(touching.validate().is_err());
+    assert_eq!(touching.self_intersections(), vec![]);
+    // A hole poking out through the right side of the boundary.
+    let mut poly = square(0, 0, 4);
+    poly.push_ring_unchecked(vec![
+      Point::new([3, 1]),
+      Point::new([3, 3]),
+      Point::new([5, 3]),
+      Point::new([5, 1]),
+    ]);
+    assert_eq!(poly.self_intersections().len(), 2);
+  }
+
+  #[test]
+  fn bounding_polygon_triangle() {
+    let poly = Polygon::new(vec![
+      Point::new([1, 2]),
+      Point::new([5, 3]),
+      Point::new([2, 7]),
+    ])
+    .unwrap();
+    let rect = poly.bounding_polygon().unwrap();
+    assert_eq!(rect.validate().err(), None);
+    let corners: Vec<&Point<i32>> = rect.iter().collect();
+    assert_eq!(
+      corners,
+      vec![
+        &Point::new([1, 2]),
+        &Point::new([5, 2]),
+        &Point::new([5, 7]),
+        &Point::new([1, 7]),
+      ]
+    );
+  }
+
+  #[test]
+  fn bounding_polygon_degenerate() {
+    let flat = Polygon::new_unchecked(vec![
+      Point::new([0, 0]),
+      Point::new([1, 0]),
+      Point::new([2, 0]),
+    ]);
+    assert_eq!(
+      flat.bounding_polygon().err(),
+      Some(Error::CoLinearViolation)
+    );
+  }
+
+  #[test]
+  fn contains_polygon_cases() {
+    let big = square(0, 0, 10);
+    let small = square(2, 2, 3);
+    assert!(big.contains_polygon(&small));
+    assert!(!small.contains_polygon(&big));
+    // Overlapping.
+    let overlap = square(8, 8, 4);
+    assert!(!big.contains_polygon(&overlap));
+    assert!(!overlap.contains_polygon(&big));
+    // Disjoint.
+    let disjoint = square(20, 20, 4);
+    assert!(!big.contains_polygon(&disjoint));
+    assert!(!disjoint.contains_polygon(&big));
+    // Touching the boundary from the inside.
+    let touching = square(0, 2, 3);
+    assert!(!big.contains_polygon(&touching));
+    // Inside a hole.
+    let mut holed = big.clone();
+    holed.add_hole(square_points(1, 1, 6)).unwrap();
+    assert!(!holed.contains_polygon(&small));
+    assert!(holed.contains_polygon(&square(8, 1, 1)));
+  }
+
+  #[test]
+  fn ray_cast_square() {
+    let mut poly = square(0, 0, 10);
+    let origin = Point::new([2, 3]);
+    let right = Vector([1, 0]);
+    let ray = HalfLineSoS::new_directed(&origin, &right);
+    let (edge, hit, t) = poly.ray_cast(&ray).unwrap();
+    let ids: Vec<PointId> = poly.iter_boundary().map(|c| c.point_id()).collect();
+    assert_eq!(edge, IndexEdge::new(ids[1], ids[2]));
+    assert_eq!(hit, Point::new([10.0, 3.0]));
+    assert_eq!(t, 8.0);
+    // A hole in the way is hit first.
+    poly.add_hole(square_points(5, 1, 3)).unwrap();
+    let (_, hit, t) = poly.ray_cast(&ray).unwrap();
+    assert_eq!(hit, Point::new([5.0, 3.0]));
+    assert_eq!(t, 3.0);
+    // Nothing is hit from the outside when pointing away.
+    let outside = Point::new([-5, 3]);
+    let left = Vector([-1, 0]);
+    assert_eq!(
+      poly.ray_cast(&HalfLineSoS::new_directed(&outside, &left)),
+      None
+    );
+  }
+
+  #[test]
+  fn monotone_direction_zigzag() {
+    // Monotone for directions up to 45 degrees from the y-axis.
+    let zigzag = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([4, 0]),
+      Point::new([7, 3]),
+      Point::new([4, 6]),
+      Point::new([7, 9]),
+      Point::new([0, 9]),
+      Point::new([3, 6]),
+      Point::new([0, 3]),
+    ])
+    .unwrap();
+    let direction = zigzag.monotone_direction().unwrap();
+    assert!(direction.0[1].abs() > direction.0[0].abs());
+    assert!(zigzag.float().is_monotone(&direction.map(OrderedFloat)));
+    let convex = square(0, 0, 3);
+    assert!(convex.monotone_direction().is_some());
+  }
+
+  #[test]
+  fn monotone_direction_spiral() {
+    let spiral = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([10, 0]),
+      Point::new([10, 10]),
+      Point::new([2, 10]),
+      Point::new([2, 4]),
+      Point::new([6, 4]),
+      Point::new([6, 6]),
+      Point::new([4, 6]),
+      Point::new([4, 8]),
+      Point::new([8, 8]),
+      Point::new([8, 2]),
+      Point::new([0, 2]),
+    ])
+    .unwrap();
+    assert_eq!(spiral.monotone_direction(), None);
+  }
+
+  #[test]
+  fn split_square_by_diagonal() {
+    let poly = square(0, 0, 4);
+    let ids: Vec<PointId> = poly.iter_boundary().map(|c| c.point_id()).collect();
+    let (a, b) = poly.split_by_diagonal(ids[1], ids[3]).unwrap();
+    assert_eq!(a.validate().err(), None);
+    assert_eq!(b.validate().err(), None);
+    assert_eq!(a.iter().count(), 3);
+    assert_eq!(b.iter().count(), 3);
+    assert_eq!(
+      a.signed_area_2x::<i32>() + b.signed_area_2x::<i32>(),
+      poly.signed_area_2x::<i32>()
+    );
+    assert_eq!(
+      poly.split_by_diagonal(ids[0], ids[1]).err(),
+      Some(Error::InvalidDiagonal)
+    );
+    assert_eq!(
+      poly.split_by_diagonal(ids[2], ids[2]).err(),
+      Some(Error::InvalidDiagonal)
+    );
+  }
+
+  #[test]
+  fn insert_vertex_on_triangle_edge() {
+    let mut poly = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([4, 0]),
+      Point::new([0, 4]),
+    ])
+    .unwrap();
+    let ids: Vec<PointId> = poly.iter_boundary().map(|c| c.point_id()).collect();
+    let hypotenuse = IndexEdge::new(ids[2], ids[1]);
+    let mid = poly
+      .insert_vertex_on_edge(hypotenuse, Point::new([2, 2]))
+      .unwrap();
+    assert_eq!(poly.validate_weakly().err(), None);
+    assert_eq!(poly.iter_boundary().len(), 4);
+    assert_eq!(poly.cursor(mid).prev().point_id(), ids[1]);
+    assert_eq!(poly.cursor(mid).next().point_id(), ids[2]);
+    assert_eq!(poly.cursor(ids[2]).next().point_id(), ids[0]);
+    assert_eq!(
+      poly.insert_vertex_on_edge(IndexEdge::new(ids[0], ids[1]), Point::new([2, 1])),
+      Err(Error::InvalidEdge)
+    );
+    assert_eq!(
+      poly.insert_vertex_on_edge(IndexEdge::new(ids[0], ids[1]), Point::new([4, 0])),
+      Err(Error::DuplicatePoints)
+    );
+    assert_eq!(
+      poly.insert_vertex_on_edge(IndexEdge::new(ids[1], ids[2]), Point::new([2, 2])),
+      Err(Error::InvalidEdge)
+    );
+  }
+
+  #[test]
+  fn split_by_invalid_diagonal() {
+    // U-shape: the diagonal between the tips of the U runs outside.
+    let poly = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([3, 0]),
+      Point::new([3, 3]),
+      Point::new([2, 3]),
+      Point::new([2, 1]),
+      Point::new([1, 1]),
+      Point::new([1, 3]),
+      Point::new([0, 3]),
+    ])
+    .unwrap();
+    let ids: Vec<PointId> = poly.iter_boundary().map(|c| c.point_id()).collect();
+    let split = |a: usize, b: usize| poly.split_by_diagonal(ids[a], ids[b]).err();
+    // Outside.
+    assert_eq!(split(3, 6), Some(Error::InvalidDiagonal));
+    assert_eq!(split(2, 7), Some(Error::InvalidDiagonal));
+    // Crosses the boundary.
+    assert_eq!(split(1, 6), Some(Error::InvalidDiagonal));
+    // Valid from a reflex vertex.
+    assert_eq!(split(4, 0), None);
+    assert_eq!(split(5, 1), None);
+  }
+
+  #[test]
+  fn translated_and_scaled() {
+    let poly = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([4, 1]),
+      Point::new([1, 3]),
+    ])
+    .unwrap();
+    let moved = poly.translated(&Vector([5, -2]));
+    assert_eq!(moved.point(PointId(1)), &Point::new([9, -1]));
+    assert!(moved.translated(&Vector([-5, 2])).equals(&poly));
+    let center = Point::new([1, 1]);
+    for factor in [1, 2, 3, -1, -2] {
+      let scaled = poly.scaled_about(&center, factor);
+      assert_eq!(scaled.orientation(), Orientation::CounterClockWise);
+      assert_eq!(
+        scaled.signed_area_2x::<i32>(),
+        poly.signed_area_2x::<i32>() * factor * factor
+      );
+    }
+  }
+
+  #[test]
+  fn rotated_about() {
+    let poly: Polygon<f64> = square(0, 0, 2).cast();
+    let rotated = poly.rotated_about(&Point::new([1.0, 1.0]), std::f64::consts::PI);
+    assert_eq!(rotated.orientation(), Orientation::CounterClockWise);
+    let (min, max) = rotated.bounding_box();
+    assert!(min.squared_euclidean_distance::<f64>(&Point::new([0.0, 0.0])) < 1e-20);
+    assert!(max.squared_euclidean_distance::<f64>(&Point::new([2.0, 2.0])) < 1e-20);
+  }
+
+  #[test]
+  fn add_hole_valid() {
+    let mut poly = square(0, 0, 10);
+    poly.add_hole(square_points(2, 2, 2)).unwrap();
+    // Clockwise input is accepted too.
+    let mut hole = square_points(6, 6, 2);
+    hole.reverse();
+    poly.add_hole(hole).unwrap();
+    assert_eq!(poly.rings.len(), 3);
+    for ring in &poly.rings[1..] {
+      let hole: Vec<Point<i32>> = ring.iter().map(|&pid| *poly.point(pid)).collect();
+      assert_eq!(
+        Polygon::new_unchecked(hole).orientation(),
+        Orientation::ClockWise
+      );
+    }
+    assert_eq!(poly.centroid(), Point::new([5, 5]));
+  }
+
+  #[test]
+  fn rings_as_polygons_with_hole() {
+    let mut poly = square(0, 0, 10);
+    poly.add_hole(square_points(2, 2, 4)).unwrap();
+    let rings = poly.rings_as_polygons();
+    assert_eq!(rings.len(), 2);
+    for ring in &rings {
+      assert_eq!(ring.validate().err(), None);
+    }
+    assert_eq!(rings[0].signed_area::<i32>(), 100);
+    assert_eq!(rings[1].signed_area::<i32>(), 16);
+  }
+
+  #[test]
+  fn render_paths_with_hole() {
+    let mut poly = square(0, 0, 10);
+    poly.add_hole(square_points(2, 2, 2)).unwrap();
+    let paths = poly.render_paths();
+    assert_eq!(paths.len(), 2);
+    assert_eq!(
+      Polygon::new_unchecked(paths[0].clone()).orientation(),
+      Orientation::CounterClockWise
+    );
+    assert_eq!(
+      Polygon::new_unchecked(paths[1].clone()).orientation(),
+      Orientation::ClockWise
+    );
+  }
+
+  #[test]
+  fn add_hole_invalid() {
+    let mut poly = square(0, 0, 10);
+    poly.add_hole(square_points(2, 2, 4)).unwrap();
+    // Pokes outside.
+    assert_eq!(
+      poly.add_hole(square_points(8, 8, 4)),
+      Err(Error::InvalidHole)
+    );
+    // Entirely outside.
+    assert_eq!(
+      poly.add_hole(square_points(20, 20, 4)),
+      Err(Error::InvalidHole)
+    );
+    // Touches the boundary.
+    assert_eq!(
+      poly.add_hole(square_points(0, 7, 2)),
+      Err(Error::InvalidHole)
+    );
+    // Overlaps an existing hole.
+    assert_eq!(
+      poly.add_hole(square_points(5, 5, 2)),
+      Err(Error::InvalidHole)
+    );
+    // Inside an existing hole.
+    assert_eq!(
+      poly.add_hole(square_points(3, 3, 1)),
+      Err(Error::InvalidHole)
+    );
+    // Contains an existing hole.
+    assert_eq!(
+      poly.add_hole(square_points(1, 1, 7)),
+      Err(Error::InvalidHole)
+    );
+    // Not a polygon.
+    assert_eq!(
+      poly.add_hole(vec![Point::new([7, 7]), Point::new([8, 8])]),
+      Err(Error::InsufficientVertices)
+    );
+    assert_eq!(poly.rings.len(), 2);
+  }
+
+  #[test]
+  fn reverse_orientation_with_hole() {
+    let mut poly: Polygon<i32> = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([6, 0]),
+      Point::new([6, 6]),
+      Point::new([0, 6]),
+    ])
+    .unwrap();
+    poly.push_ring_unchecked(vec![
+      Point::new([2, 2]),
+      Point::new([2, 4]),
+      Point::new([4, 4]),
+      Point::new([4, 2]),
+    ]);
+    poly.reverse_orientation();
+    assert_eq!(poly.orientation(), Orientation::ClockWise);
+    let hole: Vec<Point<i32>> = poly.rings[1].iter().map(|&pid| *poly.point(pid)).collect();
+    assert_eq!(
+      Polygon::new_unchecked(hole).orientation(),
+      Orientation::CounterClockWise
+    );
+  }
+
+  #[test]
+  fn sample_interior_mean() {
+    let poly: Polygon<i32> = Polygon::new(vec![
+      Point::new([0, 0]),
+      Point::new([4, 0]),
+      Point::new([4, 1]),
+      Point::new([1, 1]),
+      Point::new([1, 3]),
+      Point::new([0, 3]),
+    ])
+    .expect("valid polygon");
+    let rng = &mut SmallRng::seed_from_u64(0);
+    let n = 20_000;
+    let mut sum = Vector([0.0, 0.0]);
+    for _ in 0..n {
+      sum += Vector(poly.sample_interior(rng).array);
+    }
+    let mean = sum / n as f64;
+    let centroid = poly.map(|v| v as f64).centroid();
+    assert!((mean.0[0] - centroid.array[0]).abs() < 0.05);
+    assert!((mean.0[1] - centroid.array[1]).abs() < 0.05);
+  }
+
+  #[test]
+  #[should_panic(expected = "does not support holes")]
+  fn sample_interior_holes() {
+    let mut poly = square(0, 0, 4);
+    poly.add_hole(square_points(1, 1, 2)).unwrap();
+    poly.sample_interior(&mut SmallRng::seed_from_u64(0));
+  }
+
   #[test]
   #[should_panic]
   fn locate_feature_fixme() {
@@ -2,11 +2,12 @@ use num_traits::*;
 use ordered_float::OrderedFloat;
 use rand::distributions::uniform::SampleUniform;
 use rand::distributions::{Distribution, Standard};
+use rand::rngs::SmallRng;
 use rand::seq::SliceRandom;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
 use std::ops::*;
 
-use crate::data::{Point, PointLocation, TriangleView, Vector};
+use crate::data::{Line, Point, PointLocation, TriangleView, Vector};
 use crate::{Error, Orientation, PolygonScalar, TotalOrd};
 
 use super::Polygon;
@@ -14,6 +15,19 @@ use super::Polygon;
 #[derive(Debug, Clone, Hash)]
 pub struct PolygonConvex<T>(Polygon<T>);
 
+/// Measures of a convex polygon found together by
+/// [`PolygonConvex::calipers`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Calipers {
+  /// Squared distance between the two vertices that are furthest apart.
+  pub diameter_squared: f64,
+  /// Smallest distance between two parallel lines enclosing the polygon.
+  pub min_width: f64,
+  /// Corners of the smallest enclosing rectangle in counter-clockwise order.
+  /// One of its sides lies on an edge of the polygon.
+  pub min_area_rectangle: [Point<f64, 2>; 4],
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // PolygonConvex
 
@@ -33,6 +47,39 @@ where
     PolygonConvex(poly)
   }
 
+  /// Convex polygon from vertices that are already in counter-clockwise
+  /// order. Skips the sorting done by
+  /// [`convex_hull`](crate::algorithms::convex_hull()) and only validates the
+  /// result.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::InsufficientVertices`] for fewer than three points and
+  /// [`Error::ConvexViolation`] if a vertex is reflex or colinear. Other
+  /// errors from [`validate`](Self::validate) are passed through.
+  ///
+  /// # Time complexity
+  /// $O(n \log n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # use rgeometry::Error;
+  /// let ccw = vec![Point::new([0, 0]), Point::new([2, 0]), Point::new([0, 2])];
+  /// assert!(PolygonConvex::from_ccw_points(ccw).is_ok());
+  /// let cw = vec![Point::new([0, 0]), Point::new([0, 2]), Point::new([2, 0])];
+  /// assert_eq!(PolygonConvex::from_ccw_points(cw).err(), Some(Error::ConvexViolation));
+  /// ```
+  pub fn from_ccw_points(points: Vec<Point<T, 2>>) -> Result<PolygonConvex<T>, Error> {
+    if points.len() < 3 {
+      return Err(Error::InsufficientVertices);
+    }
+    let convex = PolygonConvex(Polygon::new_unchecked(points));
+    convex.validate()?;
+    Ok(convex)
+  }
+
   /// Locate a point relative to a convex polygon.
   ///
   /// # Time complexity
@@ -45,20 +92,27 @@ where
     let poly = &self.0;
     let vertices = self.boundary_slice();
     let p0 = poly.point(vertices[0]);
-    let mut lower = 1;
-    let mut upper = vertices.len() - 1;
-    while lower + 1 < upper {
-      let middle = (lower + upper) / 2;
-      if Point::orient(p0, poly.point(vertices[middle]), pt) == Orientation::CounterClockWise {
-        lower = middle;
-      } else {
-        upper = middle;
-      }
-    }
+    let lower = self.fan_index(pt, Orientation::CounterClockWise);
+    let upper = lower + 1;
     let p1 = poly.point(vertices[lower]);
     let p2 = poly.point(vertices[upper]);
     let triangle = TriangleView::new_unchecked([p0, p1, p2]);
-    triangle.locate(pt)
+    match triangle.locate(pt) {
+      // Only the sides of the triangle that are polygon edges are boundary.
+      // The other sides are diagonals.
+      PointLocation::OnBoundary => {
+        let on_side = |a, b| Point::orient(a, b, pt) == Orientation::CoLinear;
+        if on_side(p1, p2)
+          || (lower == 1 && on_side(p0, p1))
+          || (upper == vertices.len() - 1 && on_side(p2, p0))
+        {
+          PointLocation::OnBoundary
+        } else {
+          PointLocation::Inside
+        }
+      }
+      location => location,
+    }
   }
 
   /// Validates the following properties:
@@ -88,9 +142,425 @@ where
     self.into()
   }
 
+  /// Convex hull of the polygon and one more point.
+  ///
+  /// Points inside or on the boundary leave the polygon unchanged. Otherwise
+  /// the vertices that are no longer on the hull (including any that would
+  /// become colinear) are replaced by `p`.
+  ///
+  /// # Time complexity
+  ///
+  /// Locating `p` and finding the edges that face it takes $O(\log n)$.
+  /// Building the returned polygon copies the vertices and takes $O(n)$.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let triangle = Polygon::new(vec![Point::new([0, 0]), Point::new([2, 0]), Point::new([0, 2])])?;
+  /// let hull = PolygonConvex::new_unchecked(triangle).insert_point(&Point::new([2, 2]));
+  /// assert_eq!(hull.iter().count(), 4);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn insert_point(&self, p: &Point<T, 2>) -> PolygonConvex<T> {
+    if self.locate(p) != PointLocation::Outside {
+      return self.clone();
+    }
+    let vertices: Vec<&Point<T, 2>> = self.iter().collect();
+    let n = vertices.len();
+    // Edges that 'p' lies to the right of, or on the extension of, are no
+    // longer part of the hull. They form a single run of edges.
+    let hidden = |i: usize| !Point::orient(vertices[i % n], vertices[(i + 1) % n], p).is_ccw();
+    let hidden_edge = if hidden(0) {
+      0
+    } else if hidden(n - 1) {
+      n - 1
+    } else {
+      // 'p' is strictly inside the angle at vertex 0 so it lies beyond the
+      // outer edge of the fan triangle in that direction.
+      self.fan_index(p, Orientation::CounterClockWise)
+    };
+    let kept_edge = if !hidden(0) {
+      0
+    } else if !hidden(n - 1) {
+      n - 1
+    } else {
+      // Both edges at vertex 0 face 'p'. The ray from 'p' through vertex 0
+      // leaves the polygon through an edge that faces away from 'p'.
+      self.fan_index(p, Orientation::ClockWise)
+    };
+    debug_assert!(hidden(hidden_edge) && !hidden(kept_edge));
+    // Walking from 'hidden_edge' towards 'kept_edge' in either direction
+    // passes hidden edges and then kept edges, so the ends of the run can be
+    // found by binary search.
+    let run_end = |step: &dyn Fn(usize) -> usize, distance: usize| {
+      let (mut lo, mut hi) = (0, distance);
+      while lo + 1 < hi {
+        let middle = (lo + hi) / 2;
+        if hidden(step(middle)) {
+          lo = middle;
+        } else {
+          hi = middle;
+        }
+      }
+      step(lo)
+    };
+    let last = run_end(
+      &|k| (hidden_edge + k) % n,
+      (kept_edge + n - hidden_edge) % n,
+    );
+    let first = run_end(
+      &|k| (hidden_edge + n - k) % n,
+      (hidden_edge + n - kept_edge) % n,
+    );
+    let in_run = |i: usize| (i + n - first) % n <= (last + n - first) % n;
+    let mut hull = Vec::with_capacity(n + 1);
+    for (i, &vertex) in vertices.iter().enumerate() {
+      // A vertex survives if at least one of its edges survives.
+      if !in_run((i + n - 1) % n) || !in_run(i) {
+        hull.push(vertex.clone());
+        if in_run(i) {
+          hull.push(p.clone());
+        }
+      }
+    }
+    PolygonConvex::new_unchecked(Polygon::new_unchecked(hull))
+  }
+
+  // Index 'i' of the fan triangle (v0, v_i, v_i+1) around vertex 0 that
+  // contains the direction to 'pt'. Vertices before that direction have 'pt'
+  // on the 'side' of the ray from v0 through them, so passing
+  // 'ClockWise' finds the direction away from 'pt' instead.
+  // O(log n)
+  fn fan_index(&self, pt: &Point<T, 2>, side: Orientation) -> usize {
+    let poly = &self.0;
+    let vertices = self.boundary_slice();
+    let p0 = poly.point(vertices[0]);
+    let mut lower = 1;
+    let mut upper = vertices.len() - 1;
+    while lower + 1 < upper {
+      let middle = (lower + upper) / 2;
+      if Point::orient(p0, poly.point(vertices[middle]), pt) == side {
+        lower = middle;
+      } else {
+        upper = middle;
+      }
+    }
+    lower
+  }
+
+  /// Line through vertex `i` and vertex `i+1` (mod n). The interior of the
+  /// polygon lies to the left of the line.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `i` is out of bounds.
+  pub fn edge_line(&self, i: usize) -> Line<'_, T> {
+    let vertices = self.boundary_slice();
+    let n = vertices.len();
+    Line::new_through(
+      self.0.point(vertices[i]),
+      self.0.point(vertices[(i + 1) % n]),
+    )
+  }
+
+  /// Outward unit normal of the edge from vertex `i` to vertex `i+1` (mod n).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `i` is out of bounds.
+  pub fn edge_normal(&self, i: usize) -> Vector<f64, 2>
+  where
+    T: ToPrimitive,
+  {
+    let vertices = self.boundary_slice();
+    let n = vertices.len();
+    let src = self.0.point(vertices[i]).to_f64();
+    let dst = self.0.point(vertices[(i + 1) % n]).to_f64();
+    let direction = dst - src;
+    let length = direction.squared_magnitude().sqrt();
+    direction.rotate_90_cw().map(|v| v / length)
+  }
+
+  /// `n` points spaced equally by arc length along the boundary, starting at
+  /// vertex 0 and walking counter-clockwise.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n + m)$ where $m$ is the number of vertices.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let square = PolygonConvex::new_unchecked(Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([2, 0]),
+  ///   Point::new([2, 2]),
+  ///   Point::new([0, 2]),
+  /// ]).unwrap());
+  /// let samples = square.sample_boundary(2);
+  /// assert_eq!(samples, vec![Point::new([0.0, 0.0]), Point::new([2.0, 2.0])]);
+  /// ```
+  pub fn sample_boundary(&self, n: usize) -> Vec<Point<f64, 2>>
+  where
+    T: ToPrimitive,
+  {
+    let vertices: Vec<Point<f64>> = self.iter().map(Point::to_f64).collect();
+    let m = vertices.len();
+    let lengths: Vec<f64> = (0..m)
+      .map(|i| {
+        (vertices[(i + 1) % m] - vertices[i])
+          .squared_magnitude()
+          .sqrt()
+      })
+      .collect();
+    let perimeter: f64 = lengths.iter().sum();
+    let step = perimeter / n.to_f64().unwrap();
+    let mut samples = Vec::with_capacity(n);
+    // Edge 'edge' covers the arc lengths from 'start' to 'start + lengths[edge]'.
+    let (mut edge, mut start) = (0, 0.0);
+    for i in 0..n {
+      let target = i.to_f64().unwrap() * step;
+      while edge + 1 < m && start + lengths[edge] < target {
+        start += lengths[edge];
+        edge += 1;
+      }
+      let t = if lengths[edge] == 0.0 {
+        0.0
+      } else {
+        ((target - start) / lengths[edge]).min(1.0)
+      };
+      samples.push(vertices[edge].lerp(&vertices[(edge + 1) % m], t));
+    }
+    samples
+  }
+
+  /// Largest axis-aligned rectangle inside the polygon, given by its
+  /// lower-left and upper-right corners.
+  ///
+  /// The area of the largest rectangle spanning `[a, b]` horizontally is
+  /// log-concave in `a` and `b`, so both are found with nested ternary
+  /// searches. The area is flat near its maximum, so the corners are only
+  /// accurate to about the square root of the floating point precision.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n + k^2 \log n)$ where $k = 100$ is the number of ternary search
+  /// steps. Splitting the boundary into chains takes $O(n)$. The nested
+  /// searches then evaluate the area about $4 k^2 = 40\,000$ times, each with
+  /// binary searches on the chains. The constant dominates unless the polygon
+  /// has many thousands of vertices.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let triangle = PolygonConvex::new_unchecked(Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([4, 0]),
+  ///   Point::new([0, 4]),
+  /// ]).unwrap());
+  /// let (min, max) = triangle.largest_inscribed_rectangle();
+  /// assert!((min.x_coord() - 0.0).abs() < 1e-6 && (max.x_coord() - 2.0).abs() < 1e-6);
+  /// assert!((min.y_coord() - 0.0).abs() < 1e-6 && (max.y_coord() - 2.0).abs() < 1e-6);
+  /// ```
+  pub fn largest_inscribed_rectangle(&self) -> (Point<f64, 2>, Point<f64, 2>)
+  where
+    T: ToPrimitive,
+  {
+    let vertices: Vec<Point<f64>> = self.iter().map(Point::to_f64).collect();
+    let m = vertices.len();
+    let lo = (0..m)
+      .min_by(|&i, &j| vertices[i].cmp(&vertices[j]))
+      .unwrap();
+    let hi = (0..m)
+      .max_by(|&i, &j| vertices[i].cmp(&vertices[j]))
+      .unwrap();
+    // Walk counter-clockwise from 'from' to 'to'.
+    let chain = |from: usize, to: usize| {
+      let mut out = vec![vertices[from]];
+      let mut i = from;
+      while i != to {
+        i = (i + 1) % m;
+        out.push(vertices[i]);
+      }
+      out
+    };
+    let lower = chain(lo, hi);
+    let mut upper = chain(hi, lo);
+    upper.reverse();
+    // Vertical extent of the tallest rectangle spanning [a, b].
+    let extent = |a: f64, b: f64| {
+      let bottom = chain_y(&lower, a, f64::min).max(chain_y(&lower, b, f64::min));
+      let top = chain_y(&upper, a, f64::max).min(chain_y(&upper, b, f64::max));
+      (bottom, top)
+    };
+    let area = |a: f64, b: f64| {
+      let (bottom, top) = extent(a, b);
+      (b - a) * (top - bottom)
+    };
+    let x_max = *vertices[hi].x_coord();
+    let best_b = |a: f64| ternary_search(a, x_max, |b| area(a, b));
+    let a = ternary_search(*vertices[lo].x_coord(), x_max, |a| area(a, best_b(a)));
+    let b = best_b(a);
+    let (bottom, top) = extent(a, b);
+    (Point::new([a, bottom]), Point::new([b, top]))
+  }
+
+  /// Diameter, minimum width and minimum-area enclosing rectangle, computed
+  /// with a single pass of rotating calipers in floating point.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let rect = PolygonConvex::new_unchecked(Polygon::new(vec![
+  ///   Point::new([0, 0]),
+  ///   Point::new([4, 0]),
+  ///   Point::new([4, 2]),
+  ///   Point::new([0, 2]),
+  /// ]).unwrap());
+  /// let calipers = rect.calipers();
+  /// assert_eq!(calipers.diameter_squared, 20.0);
+  /// assert_eq!(calipers.min_width, 2.0);
+  /// assert_eq!(calipers.min_area_rectangle[2], Point::new([4.0, 2.0]));
+  /// ```
+  pub fn calipers(&self) -> Calipers
+  where
+    T: ToPrimitive,
+  {
+    let v: Vec<[f64; 2]> = self.iter().map(|pt| pt.to_f64().array).collect();
+    let m = v.len();
+    let next = |k: usize| (k + 1) % m;
+    let sub = |a: [f64; 2], b: [f64; 2]| [a[0] - b[0], a[1] - b[1]];
+    let dot = |a: [f64; 2], b: [f64; 2]| a[0] * b[0] + a[1] * b[1];
+    let cross = |a: [f64; 2], b: [f64; 2]| a[0] * b[1] - a[1] * b[0];
+    let mut diameter_squared: f64 = 0.0;
+    let mut min_width = f64::INFINITY;
+    let mut min_area = f64::INFINITY;
+    let mut min_area_rectangle = [Point::new([0.0, 0.0]); 4];
+    // Vertex furthest from the current edge and the extreme vertices along
+    // it. All three only move forward.
+    let (mut far, mut right, mut left) = (1, 1, 0);
+    for i in 0..m {
+      let a = v[i];
+      let edge = sub(v[next(i)], a);
+      let height_of = |k: usize| cross(edge, sub(v[k], a));
+      let along = |k: usize| dot(edge, sub(v[k], a));
+      while height_of(next(far)) > height_of(far) {
+        far = next(far);
+      }
+      while along(next(right)) > along(right) {
+        right = next(right);
+      }
+      if i == 0 {
+        left = far;
+      }
+      while along(next(left)) < along(left) {
+        left = next(left);
+      }
+      // Antipodal pairs. 'next(far)' covers edges parallel to this one.
+      for p in [a, v[next(i)]] {
+        for q in [v[far], v[next(far)]] {
+          let d = sub(p, q);
+          diameter_squared = diameter_squared.max(dot(d, d));
+        }
+      }
+      let length = dot(edge, edge).sqrt();
+      let u = [edge[0] / length, edge[1] / length];
+      let height = height_of(far) / length;
+      min_width = min_width.min(height);
+      let lo = along(left) / length;
+      let hi = along(right) / length;
+      let area = (hi - lo) * height;
+      if area < min_area {
+        min_area = area;
+        // 's' units along the edge and 't' units towards the interior.
+        let corner =
+          |s: f64, t: f64| Point::new([a[0] + u[0] * s - u[1] * t, a[1] + u[1] * s + u[0] * t]);
+        min_area_rectangle = [
+          corner(lo, 0.0),
+          corner(hi, 0.0),
+          corner(hi, height),
+          corner(lo, height),
+        ];
+      }
+    }
+    Calipers {
+      diameter_squared,
+      min_width,
+      min_area_rectangle,
+    }
+  }
+
+  /// True if every vertex of `self` is inside or on the boundary of `other`.
+  /// For convex polygons this means that `self` is entirely contained in
+  /// `other`.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n \log m)$
+  pub fn nested_within(&self, other: &PolygonConvex<T>) -> bool {
+    self
+      .iter()
+      .all(|pt| other.locate(pt) != PointLocation::Outside)
+  }
+
+  /// Find an axis that separates two convex polygons.
+  ///
+  /// The candidates are the outward edge normals of both polygons. Returns
+  /// `None` if the polygons overlap. Polygons that merely touch are considered
+  /// to overlap. Projections are compared exactly.
+  ///
+  /// # Panics
+  ///
+  /// May panic for bounded types (i8, isize, etc) if the difference between
+  /// two coordinates overflows.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(n m)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # fn main() -> Result<(), rgeometry::Error> {
+  /// let a = Polygon::new(vec![Point::new([0, 0]), Point::new([2, 0]), Point::new([0, 2])])?;
+  /// let b = Polygon::new(vec![Point::new([2, 2]), Point::new([3, 2]), Point::new([2, 3])])?;
+  /// let a = PolygonConvex::new_unchecked(a);
+  /// let b = PolygonConvex::new_unchecked(b);
+  /// assert_eq!(a.separating_axis(&b), Some(Vector([2, 2])));
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn separating_axis(&self, other: &PolygonConvex<T>) -> Option<Vector<T, 2>> {
+    // An edge normal separates if every vertex of the other polygon lies
+    // strictly beyond the edge.
+    let separates = |poly: &PolygonConvex<T>, other: &PolygonConvex<T>| {
+      poly.iter_boundary().find_map(|cursor| {
+        let src = cursor.point();
+        let normal = (cursor.next().point() - src).rotate_90_cw();
+        other
+          .iter()
+          .all(|pt| normal.cmp_along(pt, src) == std::cmp::Ordering::Greater)
+          .then_some(normal)
+      })
+    };
+    separates(self, other).or_else(|| separates(other, self))
+  }
+
   /// Uniformly sample a random convex polygon.
   ///
   /// The output polygon is rooted in `(0,0)`, grows upwards, and has a height and width of [`T::max_value()`](Bounded::max_value).
+  /// Use [`random_in_range`](Self::random_in_range) for smaller polygons.
   ///
   /// # Time complexity
   /// $O(n \log n)$
@@ -112,10 +582,46 @@ where
     T: Bounded + PolygonScalar + SampleUniform,
     R: Rng + ?Sized,
   {
+    PolygonConvex::random_in_range(n, Bounded::max_value(), rng)
+  }
+
+  /// Uniformly sample a random convex polygon with a height and width of
+  /// `max`.
+  ///
+  /// The output polygon is rooted in `(0,0)` and grows upwards, like
+  /// [`random`](Self::random).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `max` isn't positive.
+  ///
+  /// # Time complexity
+  /// $O(n \log n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # use rand::SeedableRng;
+  /// let mut rng = rand::rngs::SmallRng::seed_from_u64(0);
+  /// let convex: PolygonConvex<i32> = PolygonConvex::random_in_range(10, 100, &mut rng);
+  /// let (min, max) = convex.bounding_box();
+  /// assert_eq!(max.x_coord() - min.x_coord(), 100);
+  /// assert_eq!(max.y_coord() - min.y_coord(), 100);
+  /// ```
+  pub fn random_in_range<R>(n: usize, max: T, rng: &mut R) -> PolygonConvex<T>
+  where
+    T: PolygonScalar + SampleUniform,
+    R: Rng + ?Sized,
+  {
+    assert!(
+      max > T::from_constant(0),
+      "random convex polygons need a positive size"
+    );
     let n = n.max(3);
     loop {
       let vs = {
-        let mut vs = random_vectors(n, rng);
+        let mut vs = random_vectors(n, &max, rng);
         Vector::sort_around(&mut vs);
         vs
       };
@@ -138,6 +644,32 @@ where
       }
     }
   }
+
+  /// Vertices of a random convex polygon with a height and width of `max`,
+  /// in counter-clockwise order. The same seed always gives the same points.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `max` isn't positive.
+  ///
+  /// # Time complexity
+  /// $O(n \log n)$
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let pts: Vec<Point<i32>> = PolygonConvex::random_points(10, 100, 42);
+  /// assert_eq!(pts, PolygonConvex::random_points(10, 100, 42));
+  /// ```
+  pub fn random_points(n: usize, max: T, seed: u64) -> Vec<Point<T, 2>>
+  where
+    T: SampleUniform,
+  {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let convex = PolygonConvex::random_in_range(n, max, &mut rng);
+    convex.iter_boundary().map(|c| c.point().clone()).collect()
+  }
 }
 
 impl PolygonConvex<OrderedFloat<f64>> {
@@ -179,20 +711,19 @@ impl Distribution<PolygonConvex<isize>> for Standard {
 // Helper functions
 
 // Property: random_between(n, max, &mut rng).sum::<usize>() == max
-fn random_between_iter<T, R>(n: usize, rng: &mut R) -> impl Iterator<Item = T>
+fn random_between_iter<T, R>(n: usize, max: &T, rng: &mut R) -> impl Iterator<Item = T>
 where
-  T: PolygonScalar + Bounded + SampleUniform,
+  T: PolygonScalar + SampleUniform,
   R: Rng + ?Sized,
 {
   let zero: T = T::from_constant(0);
-  let max: T = Bounded::max_value();
   assert!(n > 0);
   let mut pts = Vec::with_capacity(n);
   while pts.len() < n - 1 {
     pts.push(rng.gen_range(zero.clone()..max.clone()));
   }
   pts.sort_unstable_by(TotalOrd::total_cmp);
-  pts.push(max);
+  pts.push(max.clone());
   pts.into_iter().scan(zero, |from, x| {
     let out = x.clone() - (*from).clone();
     *from = x;
@@ -201,47 +732,315 @@ where
 }
 
 // Property: random_between_zero(10, 100, &mut rng).iter().sum::<isize>() == 0
-fn random_between_zero<T, R>(n: usize, rng: &mut R) -> Vec<T>
+fn random_between_zero<T, R>(n: usize, max: &T, rng: &mut R) -> Vec<T>
 where
-  T: Bounded + PolygonScalar + SampleUniform,
+  T: PolygonScalar + SampleUniform,
   R: Rng + ?Sized,
 {
   assert!(n >= 2);
   let n_positive = rng.gen_range(1..n); // [1;n[
   let n_negative = n - n_positive;
   assert!(n_positive + n_negative == n);
-  let positive = random_between_iter(n_positive, rng);
-  let negative = random_between_iter(n_negative, rng).map(|i: T| -i);
+  let positive = random_between_iter(n_positive, max, rng);
+  let negative = random_between_iter(n_negative, max, rng).map(|i: T| -i);
   let mut result: Vec<T> = positive.chain(negative).collect();
   result.shuffle(rng);
   result
 }
 
 // Random vectors that sum to zero.
-fn random_vectors<T, R>(n: usize, rng: &mut R) -> Vec<Vector<T, 2>>
+fn random_vectors<T, R>(n: usize, max: &T, rng: &mut R) -> Vec<Vector<T, 2>>
 where
-  T: Bounded + PolygonScalar + SampleUniform,
+  T: PolygonScalar + SampleUniform,
   R: Rng + ?Sized,
 {
-  random_between_zero(n, rng)
+  random_between_zero(n, max, rng)
     .into_iter()
-    .zip(random_between_zero(n, rng))
+    .zip(random_between_zero(n, max, rng))
     .map(|(a, b)| Vector([a, b]))
     .collect()
 }
 
+// Height of an x-monotone chain at 'x'. Vertices exactly at 'x' are combined
+// with 'pick', which resolves vertical edges.
+fn chain_y(chain: &[Point<f64>], x: f64, pick: fn(f64, f64) -> f64) -> f64 {
+  let i = chain.partition_point(|pt| *pt.x_coord() < x);
+  let j = chain.partition_point(|pt| *pt.x_coord() <= x);
+  if i < j {
+    chain[i..j]
+      .iter()
+      .map(|pt| *pt.y_coord())
+      .reduce(pick)
+      .unwrap()
+  } else if i == 0 || i == chain.len() {
+    *chain[i.min(chain.len() - 1)].y_coord()
+  } else {
+    let (p, q) = (&chain[i - 1], &chain[i]);
+    let t = (x - p.x_coord()) / (q.x_coord() - p.x_coord());
+    p.y_coord() + t * (q.y_coord() - p.y_coord())
+  }
+}
+
+// Argument that maximizes a unimodal function on [lo, hi]. Evaluates 'f'
+// 200 times.
+fn ternary_search(mut lo: f64, mut hi: f64, f: impl Fn(f64) -> f64) -> f64 {
+  for _ in 0..100 {
+    let m1 = lo + (hi - lo) / 3.0;
+    let m2 = hi - (hi - lo) / 3.0;
+    if f(m1) < f(m2) {
+      lo = m1;
+    } else {
+      hi = m2;
+    }
+  }
+  (lo + hi) / 2.0
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Tests
 
 #[cfg(test)]
 mod tests {
   use super::*;
-  use rand::rngs::SmallRng;
-  use rand::SeedableRng;
+  use crate::data::Direction;
+  use crate::testing::square_convex;
 
   use proptest::prelude::*;
   use proptest::proptest as proptest_block;
 
+  #[test]
+  fn edge_lines_and_normals() {
+    let square = PolygonConvex::new_unchecked(
+      Polygon::new(vec![
+        Point::new([0, 0]),
+        Point::new([2, 0]),
+        Point::new([2, 2]),
+        Point::new([0, 2]),
+      ])
+      .unwrap(),
+    );
+    let normals: Vec<Vector<f64, 2>> = (0..4).map(|i| square.edge_normal(i)).collect();
+    assert_eq!(
+      normals,
+      vec![
+        Vector([0.0, -1.0]),
+        Vector([1.0, 0.0]),
+        Vector([0.0, 1.0]),
+        Vector([-1.0, 0.0]),
+      ]
+    );
+    let interior = Point::new([1, 1]);
+    for i in 0..4 {
+      let line = square.edge_line(i);
+      let Direction::Through(dst) = line.direction else {
+        panic!("edge lines pass through the next vertex")
+      };
+      assert_eq!(
+        Point::orient(line.origin, dst, &interior),
+        Orientation::CounterClockWise
+      );
+    }
+  }
+
+  #[test]
+  fn from_ccw_circle_samples() {
+    let n = 64;
+    let circle: Vec<Point<f64>> = (0..n)
+      .map(|i| {
+        let angle = std::f64::consts::TAU * i.to_f64().unwrap() / n.to_f64().unwrap();
+        Point::new([angle.cos(), angle.sin()])
+      })
+      .collect();
+    let convex = PolygonConvex::from_ccw_points(circle.clone()).unwrap();
+    assert_eq!(convex.iter().count(), n);
+    let mut concave = circle;
+    concave.swap(10, 11);
+    assert_eq!(
+      PolygonConvex::from_ccw_points(concave).err(),
+      Some(Error::ConvexViolation)
+    );
+    assert_eq!(
+      PolygonConvex::from_ccw_points(vec![Point::new([0.0, 0.0]), Point::new([1.0, 0.0])]).err(),
+      Some(Error::InsufficientVertices)
+    );
+  }
+
+  #[test]
+  fn sample_unit_square() {
+    let sq = square_convex(0, 0, 1);
+    assert_eq!(
+      sq.sample_boundary(4),
+      vec![
+        Point::new([0.0, 0.0]),
+        Point::new([1.0, 0.0]),
+        Point::new([1.0, 1.0]),
+        Point::new([0.0, 1.0]),
+      ]
+    );
+    assert_eq!(
+      sq.sample_boundary(8),
+      vec![
+        Point::new([0.0, 0.0]),
+        Point::new([0.5, 0.0]),
+        Point::new([1.0, 0.0]),
+        Point::new([1.0, 0.5]),
+        Point::new([1.0, 1.0]),
+        Point::new([0.5, 1.0]),
+        Point::new([0.0, 1.0]),
+        Point::new([0.0, 0.5]),
+      ]
+    );
+    assert!(sq.sample_boundary(0).is_empty());
+  }
+
+  #[test]
+  fn largest_inscribed_rectangle_cases() {
+    let close = |a: Point<f64>, b: [f64; 2]| {
+      (a.x_coord() - b[0]).abs() < 1e-6 && (a.y_coord() - b[1]).abs() < 1e-6
+    };
+    let (min, max) = square_convex(0, 0, 1).largest_inscribed_rectangle();
+    assert!(close(min, [0.0, 0.0]) && close(max, [1.0, 1.0]));
+    let diamond = PolygonConvex::new_unchecked(
+      Polygon::new(vec![
+        Point::new([1, 0]),
+        Point::new([2, 1]),
+        Point::new([1, 2]),
+        Point::new([0, 1]),
+      ])
+      .unwrap(),
+    );
+    let (min, max) = diamond.largest_inscribed_rectangle();
+    assert!(close(min, [0.5, 0.5]) && close(max, [1.5, 1.5]));
+  }
+
+  #[test]
+  fn random_points_seeded() {
+    let a: Vec<Point<i32>> = PolygonConvex::random_points(20, 1000, 7);
+    let b: Vec<Point<i32>> = PolygonConvex::random_points(20, 1000, 7);
+    let c: Vec<Point<i32>> = PolygonConvex::random_points(20, 1000, 8);
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    let poly = PolygonConvex::new_unchecked(Polygon::new(a).unwrap());
+    assert_eq!(poly.validate().err(), None);
+  }
+
+  #[test]
+  fn insert_point_cases() {
+    let sq = square_convex(0, 0, 4);
+    // Inside and on the boundary.
+    assert!(sq.insert_point(&Point::new([1, 1])).equals(&sq));
+    assert!(sq.insert_point(&Point::new([4, 2])).equals(&sq));
+    // Outside a single edge.
+    let hull = sq.insert_point(&Point::new([6, 2]));
+    assert_eq!(hull.validate().err(), None);
+    assert_eq!(hull.iter().count(), 5);
+    // On the extension of an edge.
+    let hull = sq.insert_point(&Point::new([6, 0]));
+    assert_eq!(hull.validate().err(), None);
+    assert!(hull.equals(
+      &Polygon::new(vec![
+        Point::new([0, 0]),
+        Point::new([6, 0]),
+        Point::new([4, 4]),
+        Point::new([0, 4]),
+      ])
+      .unwrap()
+    ));
+    // Hiding several vertices.
+    let hull = sq.insert_point(&Point::new([10, 10]));
+    assert_eq!(hull.validate().err(), None);
+    assert_eq!(hull.iter().count(), 4);
+  }
+
+  #[test]
+  fn locate_diagonal() {
+    let sq = square_convex(0, 0, 2);
+    assert_eq!(sq.locate(&Point::new([1, 1])), PointLocation::Inside);
+    assert_eq!(sq.locate(&Point::new([1, 0])), PointLocation::OnBoundary);
+    assert_eq!(sq.locate(&Point::new([0, 1])), PointLocation::OnBoundary);
+    assert_eq!(sq.locate(&Point::new([2, 2])), PointLocation::OnBoundary);
+    assert_eq!(sq.locate(&Point::new([3, 3])), PointLocation::Outside);
+  }
+
+  #[test]
+  fn nested_within_squares() {
+    let big = square_convex(0, 0, 10);
+    assert!(square_convex(2, 2, 3).nested_within(&big));
+    assert!(!big.nested_within(&square_convex(2, 2, 3)));
+    assert!(!square_convex(8, 8, 4).nested_within(&big));
+    assert!(big.nested_within(&big));
+    assert!(square_convex(0, 0, 5).nested_within(&big));
+  }
+
+  #[test]
+  fn separating_axis_squares() {
+    let a = square_convex(0, 0, 4);
+    assert_eq!(a.separating_axis(&square_convex(2, 2, 4)), None);
+    assert_eq!(a.separating_axis(&square_convex(1, 1, 1)), None);
+    let axis = a.separating_axis(&square_convex(6, 1, 2)).unwrap();
+    assert_eq!(axis.0[1], 0);
+    assert!(axis.0[0] > 0);
+    // Sharing an edge or a corner counts as overlapping.
+    assert_eq!(a.separating_axis(&square_convex(4, 0, 4)), None);
+    assert_eq!(a.separating_axis(&square_convex(4, 4, 4)), None);
+  }
+
+  // Reference measures computed edge by edge without calipers.
+  fn brute_force_calipers(poly: &PolygonConvex<i8>) -> (f64, f64, f64) {
+    let v: Vec<Point<f64>> = poly.iter().map(Point::to_f64).collect();
+    let m = v.len();
+    let mut diameter_squared: f64 = 0.0;
+    for p in &v {
+      for q in &v {
+        diameter_squared = diameter_squared.max(p.squared_euclidean_distance(q));
+      }
+    }
+    let (mut min_width, mut min_area) = (f64::INFINITY, f64::INFINITY);
+    for i in 0..m {
+      let edge = v[(i + 1) % m] - v[i];
+      let length = edge.squared_magnitude().sqrt();
+      let heights = v.iter().map(|p| {
+        let d = *p - v[i];
+        (edge.0[0] * d.0[1] - edge.0[1] * d.0[0]) / length
+      });
+      let along: Vec<f64> = v
+        .iter()
+        .map(|p| {
+          let d = *p - v[i];
+          (edge.0[0] * d.0[0] + edge.0[1] * d.0[1]) / length
+        })
+        .collect();
+      let height = heights.fold(0.0, f64::max);
+      let span = along.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+        - along.iter().copied().fold(f64::INFINITY, f64::min);
+      min_width = min_width.min(height);
+      min_area = min_area.min(span * height);
+    }
+    (diameter_squared, min_width, min_area)
+  }
+
+  fn rectangle_area(rect: &[Point<f64, 2>; 4]) -> f64 {
+    let side = |a: &Point<f64>, b: &Point<f64>| a.squared_euclidean_distance::<f64>(b).sqrt();
+    side(&rect[0], &rect[1]) * side(&rect[1], &rect[2])
+  }
+
+  #[test]
+  fn calipers_triangle() {
+    let triangle = PolygonConvex::new_unchecked(
+      Polygon::new(vec![
+        Point::new([0, 0]),
+        Point::new([4, 0]),
+        Point::new([0, 3]),
+      ])
+      .unwrap(),
+    );
+    let calipers = triangle.calipers();
+    assert_eq!(calipers.diameter_squared, 25.0);
+    // Height over the hypotenuse.
+    assert!((calipers.min_width - 2.4).abs() < 1e-12);
+    assert!((rectangle_area(&calipers.min_area_rectangle) - 12.0).abs() < 1e-12);
+  }
+
   proptest_block! {
     // These traits are usually derived but let's not rely on that.
     #[test]
@@ -250,6 +1049,37 @@ mod tests {
       let _ = poly.clone();
     }
 
+    #[test]
+    fn insert_point_prop(pts in proptest::collection::vec(any::<Point<i8>>(), 3..30)) {
+      if let Ok(mut hull) = crate::algorithms::convex_hull(pts[..3].to_vec()) {
+        for pt in &pts[3..] {
+          hull = hull.insert_point(pt);
+          prop_assert_eq!(hull.validate().err(), None);
+        }
+        let batch = crate::algorithms::convex_hull(pts).unwrap();
+        prop_assert!(hull.equals(&batch));
+      }
+    }
+
+    #[test]
+    fn insert_point_into_polygon_prop(poly: PolygonConvex<i8>, pt: Point<i8>) {
+      let hull = poly.insert_point(&pt);
+      prop_assert_eq!(hull.validate().err(), None);
+      let mut pts: Vec<Point<i8>> = poly.iter().cloned().collect();
+      pts.push(pt);
+      prop_assert!(hull.equals(&crate::algorithms::convex_hull(pts).unwrap()));
+    }
+
+    #[test]
+    fn calipers_prop(poly: PolygonConvex<i8>) {
+      let calipers = poly.calipers();
+      let (diameter_squared, min_width, min_area) = brute_force_calipers(&poly);
+      prop_assert_eq!(calipers.diameter_squared, diameter_squared);
+      prop_assert!((calipers.min_width - min_width).abs() < 1e-9);
+      prop_assert!((rectangle_area(&calipers.min_area_rectangle) - min_area).abs() < 1e-6);
+      prop_assert!(calipers.min_width <= calipers.diameter_squared.sqrt());
+    }
+
     #[test]
     fn fuzz_validate(poly: Polygon<i8>) {
       let convex = PolygonConvex::new_unchecked(poly);
@@ -271,6 +1101,38 @@ mod tests {
       prop_assert_eq!(height, i8::MAX);
     }
 
+    #[test]
+    fn locate_prop(poly: PolygonConvex<i8>, pt: Point<i8>) {
+      prop_assert_eq!(poly.locate(&pt), poly.polygon().locate(&pt));
+    }
+
+    #[test]
+    fn largest_inscribed_rectangle_inside(poly: PolygonConvex<i8>) {
+      let (min, max) = poly.largest_inscribed_rectangle();
+      prop_assert!(min.x_coord() <= max.x_coord() && min.y_coord() <= max.y_coord());
+      let corners = [[min.x_coord(), min.y_coord()], [max.x_coord(), min.y_coord()], [max.x_coord(), max.y_coord()], [min.x_coord(), max.y_coord()]];
+      for cursor in poly.iter_boundary() {
+        let src = cursor.point().to_f64();
+        let dir = cursor.next().point().to_f64() - src;
+        for [x, y] in corners {
+          // Distance to the left of the edge, scaled by the edge length.
+          let cross = dir.0[0] * (y - src.y_coord()) - dir.0[1] * (x - src.x_coord());
+          prop_assert!(cross > -1e-6, "corner ({x}, {y}) is outside");
+        }
+      }
+    }
+
+    #[test]
+    fn random_in_range_prop(n in 3..50_usize, max in 1..1000_i32, seed: u64) {
+      let mut rng = SmallRng::seed_from_u64(seed);
+      let poly = PolygonConvex::random_in_range(n, max, &mut rng);
+      prop_assert_eq!(poly.validate().err(), None);
+      let (min, max_corner) = poly.bounding_box();
+      prop_assert_eq!(min.y_coord(), &0);
+      prop_assert_eq!(max_corner.x_coord() - min.x_coord(), max);
+      prop_assert_eq!(max_corner.y_coord() - min.y_coord(), max);
+    }
+
     #[test]
     fn all_random_convex_polygons_are_valid_i64(poly: PolygonConvex<i64>) {
       prop_assert_eq!(poly.validate().err(), None)
@@ -279,21 +1141,21 @@ mod tests {
     #[test]
     fn sum_to_max(n in 1..1000, seed: u64) {
       let mut rng = SmallRng::seed_from_u64(seed);
-      let vecs = random_between_iter::<i8, _>(n as usize, &mut rng);
+      let vecs = random_between_iter::<i8, _>(n as usize, &i8::MAX, &mut rng);
       prop_assert_eq!(vecs.sum::<i8>(), i8::MAX);
 
-      let vecs = random_between_iter::<i64, _>(n as usize, &mut rng);
+      let vecs = random_between_iter::<i64, _>(n as usize, &i64::MAX, &mut rng);
       prop_assert_eq!(vecs.sum::<i64>(), i64::MAX);
     }
 
     #[test]
     fn random_between_zero_properties(n in 2..1000, seed: u64) {
       let mut rng = SmallRng::seed_from_u64(seed);
-      let vecs: Vec<i8> = random_between_zero(n as usize, &mut rng);
+      let vecs: Vec<i8> = random_between_zero(n as usize, &i8::MAX, &mut rng);
       prop_assert_eq!(vecs.iter().sum::<i8>(), 0);
       prop_assert_eq!(vecs.len(), n as usize);
 
-      let vecs: Vec<i64> = random_between_zero(n as usize, &mut rng);
+      let vecs: Vec<i64> = random_between_zero(n as usize, &i64::MAX, &mut rng);
       prop_assert_eq!(vecs.iter().sum::<i64>(), 0);
       prop_assert_eq!(vecs.len(), n as usize);
     }
@@ -301,7 +1163,7 @@ mod tests {
     #[test]
     fn sum_to_zero_vector(n in 2..1000, seed: u64) {
       let mut rng = SmallRng::seed_from_u64(seed);
-      let vecs: Vec<Vector<i8, 2>> = random_vectors(n as usize, &mut rng);
+      let vecs: Vec<Vector<i8, 2>> = random_vectors(n as usize, &i8::MAX, &mut rng);
       prop_assert_eq!(vecs.into_iter().sum::<Vector<i8, 2>>(), Vector([0, 0]))
     }
   }
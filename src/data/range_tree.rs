@@ -0,0 +1,154 @@
+use std::cmp::Ordering;
+
+use super::Point;
+use crate::PolygonScalar;
+
+/// Static 2D range tree for counting points in axis-aligned rectangles.
+///
+/// The points are sorted by their x-coordinate and stored in a segment tree.
+/// Each node keeps the sorted y-coordinates of the points below it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// let tree = RangeTree::new(&[
+///   Point::new([0, 0]),
+///   Point::new([5, 5]),
+///   Point::new([9, 1]),
+/// ]);
+/// assert_eq!(tree.count(&Point::new([0, 0]), &Point::new([5, 5])), 2);
+/// assert_eq!(tree.count(&Point::new([6, 2]), &Point::new([9, 9])), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RangeTree<T> {
+  // Sorted x-coordinates.
+  xs: Vec<T>,
+  // Sorted y-coordinates for each node. The leaves start at 'xs.len()'.
+  nodes: Vec<Vec<T>>,
+}
+
+impl<T: PolygonScalar> RangeTree<T> {
+  /// # Time complexity
+  ///
+  /// $O(n \log n)$
+  pub fn new(points: &[Point<T>]) -> RangeTree<T> {
+    let mut sorted: Vec<&Point<T>> = points.iter().collect();
+    sorted.sort_unstable_by(|a, b| a.x_coord().total_cmp(b.x_coord()));
+    let n = sorted.len();
+    let mut nodes: Vec<Vec<T>> = vec![Vec::new(); n];
+    nodes.extend(sorted.iter().map(|pt| vec![pt.y_coord().clone()]));
+    for i in (1..n).rev() {
+      nodes[i] = merge(&nodes[2 * i], &nodes[2 * i + 1]);
+    }
+    let xs = sorted.into_iter().map(|pt| pt.x_coord().clone()).collect();
+    RangeTree { xs, nodes }
+  }
+
+  pub fn len(&self) -> usize {
+    self.xs.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.xs.is_empty()
+  }
+
+  /// Number of points with `lo <= pt <= hi` in both coordinates.
+  ///
+  /// # Time complexity
+  ///
+  /// $O(\log^2 n)$
+  pub fn count(&self, lo: &Point<T>, hi: &Point<T>) -> usize {
+    let n = self.xs.len();
+    let mut l = n
+      + self
+        .xs
+        .partition_point(|x| x.total_cmp(lo.x_coord()) == Ordering::Less);
+    let mut r = n
+      + self
+        .xs
+        .partition_point(|x| x.total_cmp(hi.x_coord()) != Ordering::Greater);
+    let count_node = |ys: &[T]| {
+      let below = ys.partition_point(|y| y.total_cmp(lo.y_coord()) == Ordering::Less);
+      let upto = ys.partition_point(|y| y.total_cmp(hi.y_coord()) != Ordering::Greater);
+      upto.saturating_sub(below)
+    };
+    let mut total = 0;
+    while l < r {
+      if l % 2 == 1 {
+        total += count_node(&self.nodes[l]);
+        l += 1;
+      }
+      if r % 2 == 1 {
+        r -= 1;
+        total += count_node(&self.nodes[r]);
+      }
+      l /= 2;
+      r /= 2;
+    }
+    total
+  }
+}
+
+fn merge<T: PolygonScalar>(a: &[T], b: &[T]) -> Vec<T> {
+  let mut out = Vec::with_capacity(a.len() + b.len());
+  let (mut i, mut j) = (0, 0);
+  while i < a.len() && j < b.len() {
+    if a[i].total_cmp(&b[j]) == Ordering::Greater {
+      out.push(b[j].clone());
+      j += 1;
+    } else {
+      out.push(a[i].clone());
+      i += 1;
+    }
+  }
+  out.extend_from_slice(&a[i..]);
+  out.extend_from_slice(&b[j..]);
+  out
+}
+
+#[cfg(test)]
+#[cfg(not(tarpaulin_include))]
+mod tests {
+  use super::*;
+
+  use proptest::collection::vec;
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  #[test]
+  fn grid() {
+    let pts: Vec<Point<i32>> = (0..5)
+      .flat_map(|x| (0..5).map(move |y| Point::new([x, y])))
+      .collect();
+    let tree = RangeTree::new(&pts);
+    let count = |lo: [i32; 2], hi: [i32; 2]| tree.count(&Point::new(lo), &Point::new(hi));
+    assert_eq!(count([0, 0], [4, 4]), 25);
+    assert_eq!(count([-10, -10], [10, 10]), 25);
+    assert_eq!(count([1, 1], [2, 3]), 6);
+    assert_eq!(count([4, 0], [4, 4]), 5);
+    assert_eq!(count([2, 2], [2, 2]), 1);
+    assert_eq!(count([5, 5], [9, 9]), 0);
+    assert_eq!(count([3, 3], [1, 1]), 0);
+  }
+
+  #[test]
+  fn empty() {
+    let tree: RangeTree<i32> = RangeTree::new(&[]);
+    assert_eq!(tree.count(&Point::new([0, 0]), &Point::new([1, 1])), 0);
+  }
+
+  #[proptest]
+  fn count_prop(
+    #[strategy(vec(any::<Point<i8>>(), 0..100))] pts: Vec<Point<i8>>,
+    lo: Point<i8>,
+    hi: Point<i8>,
+  ) {
+    let tree = RangeTree::new(&pts);
+    let brute = pts
+      .iter()
+      .filter(|pt| (0..2).all(|i| lo.array[i] <= pt.array[i] && pt.array[i] <= hi.array[i]))
+      .count();
+    prop_assert_eq!(tree.count(&lo, &hi), brute);
+  }
+}
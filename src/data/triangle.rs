@@ -4,6 +4,7 @@ use claims::debug_assert_ok;
 use num_traits::*;
 use rand::distributions::uniform::SampleUniform;
 use rand::Rng;
+use std::cmp::Ordering;
 
 // FIXME: Support n-dimensional triangles?
 #[derive(Debug, Clone)]
@@ -42,6 +43,28 @@ where
   pub fn view(&self) -> TriangleView<'_, T> {
     TriangleView([&self.0[0], &self.0[1], &self.0[2]])
   }
+
+  pub fn barycentric(&self, p: &Point<T, 2>) -> [f64; 3]
+  where
+    T: ToPrimitive,
+  {
+    self.view().barycentric(p)
+  }
+
+  pub fn contains_barycentric(&self, p: &Point<T, 2>) -> bool
+  where
+    T: ToPrimitive,
+  {
+    self.view().contains_barycentric(p)
+  }
+
+  /// True if `p` lies strictly inside the circle through the three vertices.
+  /// Points on the circle, including the vertices themselves, are not inside.
+  /// The test is exact and doesn't depend on the orientation of the vertices.
+  /// See [`TriangleView::in_circumcircle`].
+  pub fn in_circumcircle(&self, p: &Point<T, 2>) -> bool {
+    self.view().in_circumcircle(p)
+  }
 }
 
 pub struct TriangleView<'a, T>([&'a Point<T, 2>; 3]);
@@ -108,6 +131,44 @@ where
     self.signed_area_2x::<F>() / F::from_usize(2).unwrap()
   }
 
+  /// Absolute area, regardless of orientation.
+  pub fn area<F>(&self) -> F
+  where
+    T: PolygonScalar + Into<F>,
+    F: NumOps<F, F> + FromPrimitive + Signed + Clone,
+  {
+    self.signed_area::<F>().abs()
+  }
+
+  /// True if the three vertices are colinear.
+  pub fn is_degenerate(&self) -> bool {
+    let [a, b, c] = self.0;
+    Orientation::new(&a.array, &b.array, &c.array).is_colinear()
+  }
+
+  /// True if `p` lies strictly inside the circle through the three vertices.
+  /// Works for either orientation. Degenerate triangles have no circumcircle
+  /// and always return false.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let (a, b, c) = (Point::new([0, 0]), Point::new([4, 0]), Point::new([0, 4]));
+  /// let t = TriangleView::new_unchecked([&a, &b, &c]);
+  /// assert!(t.in_circumcircle(&Point::new([3, 3])));
+  /// assert!(!t.in_circumcircle(&Point::new([4, 4])));
+  /// ```
+  pub fn in_circumcircle(&self, p: &Point<T, 2>) -> bool {
+    let [a, b, c] = self.0;
+    let incircle = T::incircle(&a.array, &b.array, &c.array, &p.array);
+    match self.orientation() {
+      Orientation::CounterClockWise => incircle == Ordering::Greater,
+      Orientation::ClockWise => incircle == Ordering::Less,
+      Orientation::CoLinear => false,
+    }
+  }
+
   pub fn signed_area_2x<F>(&self) -> F
   where
     T: PolygonScalar + Into<F>,
@@ -127,6 +188,44 @@ where
     // x3*y1 - x1*y3
   }
 
+  /// Barycentric coordinates of `p`: the weights of the three vertices that
+  /// sum to one and reproduce `p`. Each weight is the signed area of the
+  /// triangle formed by `p` and the opposite edge, relative to the whole.
+  /// Points outside the triangle have at least one negative weight.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the triangle is degenerate.
+  pub fn barycentric(&self, p: &Point<T, 2>) -> [f64; 3]
+  where
+    T: ToPrimitive,
+  {
+    let [a, b, c] = self.0.map(Point::to_f64);
+    let p = p.to_f64();
+    let area_2x = |u: &Point<f64, 2>, v: &Point<f64, 2>, w: &Point<f64, 2>| {
+      (v.array[0] - u.array[0]) * (w.array[1] - u.array[1])
+        - (w.array[0] - u.array[0]) * (v.array[1] - u.array[1])
+    };
+    let total = area_2x(&a, &b, &c);
+    assert!(
+      total != 0.0,
+      "degenerate triangle has no barycentric coordinates"
+    );
+    [
+      area_2x(&p, &b, &c) / total,
+      area_2x(&a, &p, &c) / total,
+      area_2x(&a, &b, &p) / total,
+    ]
+  }
+
+  /// True if all barycentric coordinates of `p` are non-negative.
+  pub fn contains_barycentric(&self, p: &Point<T, 2>) -> bool
+  where
+    T: ToPrimitive,
+  {
+    self.barycentric(p).iter().all(|&w| w >= 0.0)
+  }
+
   pub fn bounding_box(&self) -> (Point<T, 2>, Point<T, 2>) {
     let min_x = self.0[0]
       .x_coord()
@@ -179,3 +278,89 @@ where
   //     u = v2^.core .-. v1^.core
   //     v = v3^.core .-. v1^.core
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  fn triangle() -> Triangle<i32> {
+    Triangle::new([Point::new([0, 0]), Point::new([3, 0]), Point::new([0, 3])]).unwrap()
+  }
+
+  #[test]
+  fn in_circumcircle_cases() {
+    let t = Triangle::new([Point::new([0, 0]), Point::new([4, 0]), Point::new([0, 4])]).unwrap();
+    // Circumcenter.
+    assert!(t.in_circumcircle(&Point::new([2, 2])));
+    assert!(!t.in_circumcircle(&Point::new([10, 10])));
+    // On the circle.
+    assert!(!t.in_circumcircle(&Point::new([4, 4])));
+    assert!(!t.in_circumcircle(&Point::new([0, 0])));
+    // Clockwise vertices describe the same circle.
+    let (a, b, c) = (Point::new([0, 0]), Point::new([0, 4]), Point::new([4, 0]));
+    let cw = TriangleView::new_unchecked([&a, &b, &c]);
+    assert!(cw.in_circumcircle(&Point::new([2, 2])));
+    assert!(!cw.in_circumcircle(&Point::new([10, 10])));
+  }
+
+  #[proptest]
+  fn in_circumcircle_f64_i8_prop(a: Point<i8>, b: Point<i8>, c: Point<i8>, p: Point<i8>) {
+    let exact = TriangleView::new_unchecked([&a, &b, &c]).in_circumcircle(&p);
+    let (a, b, c, p): (Point<f64>, Point<f64>, Point<f64>, Point<f64>) =
+      (a.cast(), b.cast(), c.cast(), p.cast());
+    prop_assert_eq!(
+      TriangleView::new_unchecked([&a, &b, &c]).in_circumcircle(&p),
+      exact
+    );
+  }
+
+  #[test]
+  fn barycentric_centroid() {
+    let w = triangle().barycentric(&Point::new([1, 1]));
+    assert_eq!(w, [1.0 / 3.0; 3]);
+  }
+
+  #[test]
+  fn barycentric_vertices() {
+    let t = triangle();
+    assert_eq!(t.barycentric(&Point::new([0, 0])), [1.0, 0.0, 0.0]);
+    assert_eq!(t.barycentric(&Point::new([3, 0])), [0.0, 1.0, 0.0]);
+    assert_eq!(t.barycentric(&Point::new([0, 3])), [0.0, 0.0, 1.0]);
+  }
+
+  #[test]
+  fn barycentric_outside() {
+    let t = triangle();
+    assert!(t.contains_barycentric(&Point::new([1, 2])));
+    assert!(!t.contains_barycentric(&Point::new([2, 2])));
+    assert!(t.barycentric(&Point::new([-1, 1]))[1] < 0.0);
+  }
+
+  #[test]
+  fn area_and_degenerate() {
+    let (a, b, c) = (Point::new([0, 0]), Point::new([1, 0]), Point::new([0, 1]));
+    let right = TriangleView::new_unchecked([&a, &b, &c]);
+    assert_eq!(right.area::<f64>(), 0.5);
+    assert!(!right.is_degenerate());
+    let clockwise = TriangleView::new_unchecked([&c, &b, &a]);
+    assert_eq!(clockwise.area::<f64>(), 0.5);
+    let d = Point::new([2, 0]);
+    assert!(TriangleView::new_unchecked([&a, &b, &d]).is_degenerate());
+    assert!(TriangleView::new_unchecked([&a, &a, &c]).is_degenerate());
+  }
+
+  #[proptest]
+  fn contains_barycentric_prop(pts: [Point<i8, 2>; 3], p: Point<i8, 2>) {
+    if let Ok(t) = Triangle::new(pts) {
+      let w = t.barycentric(&p);
+      prop_assert!((w.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+      prop_assert_eq!(
+        t.contains_barycentric(&p),
+        t.locate(&p) != PointLocation::Outside
+      );
+    }
+  }
+}
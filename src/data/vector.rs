@@ -1,6 +1,6 @@
 use array_init::{array_init, try_array_init};
 use num_rational::BigRational;
-use num_traits::NumOps;
+use num_traits::{NumOps, ToPrimitive};
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 use std::cmp::Ordering;
@@ -61,6 +61,46 @@ where
   {
     Vector(array_init(|i| self.0[i].clone().into()))
   }
+
+  /// Compare `p` and `q` by their projection onto the vector, that is by the
+  /// dot products `self·p` and `self·q`. Points are equal if the line
+  /// between them is perpendicular to the vector.
+  ///
+  /// In two dimensions the comparison is exact and never overflows. In other
+  /// dimensions the dot products are computed with the arithmetic of `T`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// # use std::cmp::Ordering;
+  /// let v = Vector([1, 1, 0]);
+  /// let p = Point::new([2, 0, 9]);
+  /// let q = Point::new([0, 3, -9]);
+  /// assert_eq!(v.cmp_along(&p, &q), Ordering::Less);
+  /// assert_eq!(v.cmp_along(&p, &Point::new([1, 1, 0])), Ordering::Equal);
+  /// ```
+  pub fn cmp_along(&self, p: &Point<T, N>, q: &Point<T, N>) -> Ordering
+  where
+    T: PolygonScalar,
+  {
+    if let (Ok(v), Ok(p), Ok(q)) = (
+      <&[T; 2]>::try_from(&self.0[..]),
+      <&[T; 2]>::try_from(&p.array[..]),
+      <&[T; 2]>::try_from(&q.array[..]),
+    ) {
+      // Rotate the vector 90 degrees counterclockwise.
+      return match Orientation::along_perp_vector(p, &Vector(v.clone()), q) {
+        Orientation::CounterClockWise => Ordering::Greater,
+        Orientation::ClockWise => Ordering::Less,
+        Orientation::CoLinear => Ordering::Equal,
+      };
+    }
+    let dot = (0..N)
+      .map(|i| self.0[i].clone() * (p.array[i].clone() - q.array[i].clone()))
+      .fold(T::from_constant(0), |acc, x| acc + x);
+    dot.total_cmp(&T::from_constant(0))
+  }
 }
 
 impl<T, const N: usize> Index<usize> for Vector<T, N> {
@@ -124,34 +164,80 @@ impl<T> Vector<T, 2> {
     Orientation::ccw_cmp_around_with(z, &self.0, &p.0, &q.0)
   }
 
-  // FIXME: rename to sort_around_origin
+  /// Sort vectors by their counter-clockwise angle from the positive x-axis.
+  /// Vectors pointing along the positive x-axis come first.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let mut vs = [Vector([0, -1]), Vector([-1, 0]), Vector([0, 1]), Vector([1, 0])];
+  /// Vector::sort_around(&mut vs);
+  /// assert_eq!(vs, [Vector([1, 0]), Vector([0, 1]), Vector([-1, 0]), Vector([0, -1])]);
+  /// ```
   // FIXME: sort by magnitude if two vectors have the same angle.
-  pub fn sort_around(pts: &mut [Vector<T, 2>])
+  pub fn sort_around(vectors: &mut [Vector<T, 2>])
   where
     T: PolygonScalar,
   {
-    let origin = [T::from_constant(0), T::from_constant(0)];
-    pts.sort_unstable_by(|a, b| {
-      Orientation::ccw_cmp_around_with(
-        &Vector([T::from_constant(1), T::from_constant(0)]),
-        &origin,
-        &a.0,
-        &b.0,
-      )
-    })
-    // L.sortBy (ccwCmpAround c <> cmpByDistanceTo c)
-  }
-
-  pub fn cmp_along(&self, p: &Point<T, 2>, q: &Point<T, 2>) -> Ordering
+    Vector::sort_around_from(vectors, &Vector::unit_right())
+  }
+
+  /// Sort vectors by their counter-clockwise angle from `start_dir`. Vectors
+  /// pointing in the direction of `start_dir` come first.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let mut vs = [Vector([0, -1]), Vector([-1, 0]), Vector([0, 1]), Vector([1, 0])];
+  /// Vector::sort_around_from(&mut vs, &Vector([0, 1]));
+  /// assert_eq!(vs, [Vector([0, 1]), Vector([-1, 0]), Vector([0, -1]), Vector([1, 0])]);
+  /// ```
+  pub fn sort_around_from(vectors: &mut [Vector<T, 2>], start_dir: &Vector<T, 2>)
   where
     T: PolygonScalar,
   {
-    // Rotate the vector 90 degrees counterclockwise.
-    match Orientation::along_perp_vector(&p.array, self, &q.array) {
-      Orientation::CounterClockWise => Ordering::Greater,
-      Orientation::ClockWise => Ordering::Less,
-      Orientation::CoLinear => Ordering::Equal,
-    }
+    let origin = [T::from_constant(0), T::from_constant(0)];
+    vectors
+      .sort_unstable_by(|a, b| Orientation::ccw_cmp_around_with(start_dir, &origin, &a.0, &b.0))
+  }
+
+  /// Rotate 90 degrees counter-clockwise. Exact for all scalar types.
+  ///
+  /// # Panics
+  ///
+  /// May panic for bounded types if a coordinate is the minimum value.
+  pub fn rotate_90_ccw(&self) -> Vector<T, 2>
+  where
+    T: Neg<Output = T> + Clone,
+  {
+    let [x, y] = self.0.clone();
+    Vector([-y, x])
+  }
+
+  /// Rotate 90 degrees clockwise. Exact for all scalar types.
+  ///
+  /// # Panics
+  ///
+  /// May panic for bounded types if a coordinate is the minimum value.
+  pub fn rotate_90_cw(&self) -> Vector<T, 2>
+  where
+    T: Neg<Output = T> + Clone,
+  {
+    let [x, y] = self.0.clone();
+    Vector([y, -x])
+  }
+
+  /// Rotate counter-clockwise by `angle` radians.
+  pub fn rotate(&self, angle: f64) -> Vector<f64, 2>
+  where
+    T: ToPrimitive,
+  {
+    let x = self.0[0].to_f64().unwrap();
+    let y = self.0[1].to_f64().unwrap();
+    let (sin, cos) = angle.sin_cos();
+    Vector([x * cos - y * sin, x * sin + y * cos])
   }
 }
 
@@ -207,6 +293,97 @@ mod tests {
     v.cmp_along(&p1, &p2);
   }
 
+  #[proptest]
+  fn rotate_90_prop(v: Vector<i16, 2>) {
+    let v = v.cast::<i32>();
+    prop_assert_eq!(v.rotate_90_ccw().rotate_90_ccw(), -v.clone());
+    prop_assert_eq!(v.rotate_90_ccw().rotate_90_cw(), v.clone());
+    let ccw = v.rotate(std::f64::consts::FRAC_PI_2);
+    let cw = v.rotate(-std::f64::consts::FRAC_PI_2);
+    for i in 0..2 {
+      prop_assert!((ccw.0[i] - f64::from(v.rotate_90_ccw().0[i])).abs() < 1e-9);
+      prop_assert!((cw.0[i] - f64::from(v.rotate_90_cw().0[i])).abs() < 1e-9);
+    }
+  }
+
+  #[test]
+  fn sort_around_axes() {
+    let mut vs = [
+      Vector([0, -1]),
+      Vector([1, 0]),
+      Vector([0, 1]),
+      Vector([-1, 0]),
+    ];
+    Vector::sort_around(&mut vs);
+    assert_eq!(
+      vs,
+      [
+        Vector([1, 0]),
+        Vector([0, 1]),
+        Vector([-1, 0]),
+        Vector([0, -1])
+      ]
+    );
+    Vector::sort_around_from(&mut vs, &Vector([-1, -1]));
+    assert_eq!(
+      vs,
+      [
+        Vector([0, -1]),
+        Vector([1, 0]),
+        Vector([0, 1]),
+        Vector([-1, 0])
+      ]
+    );
+  }
+
+  #[proptest]
+  fn sort_around_prop(vs: Vec<Vector<i8, 2>>) {
+    let mut vs: Vec<Vector<i8, 2>> = vs.into_iter().filter(|v| v.0 != [0, 0]).collect();
+    Vector::sort_around(&mut vs);
+    let angle = |v: &Vector<i8, 2>| {
+      let a = f64::from(v.0[1]).atan2(f64::from(v.0[0]));
+      if a < 0.0 {
+        a + std::f64::consts::TAU
+      } else {
+        a
+      }
+    };
+    for w in vs.windows(2) {
+      prop_assert!(angle(&w[0]) <= angle(&w[1]) + 1e-12);
+    }
+  }
+
+  #[test]
+  fn rotate_45() {
+    let v = Vector([1, 1]).rotate(std::f64::consts::FRAC_PI_4);
+    assert!(v.0[0].abs() < 1e-12);
+    assert!((v.0[1] - 2f64.sqrt()).abs() < 1e-12);
+  }
+
+  #[proptest]
+  fn cmp_along_prop_2d(v: Vector<i8, 2>, p1: Point<i8, 2>, p2: Point<i8, 2>) {
+    let dot = |p: &Point<i8, 2>| {
+      (0..2)
+        .map(|i| i32::from(v.0[i]) * i32::from(p.array[i]))
+        .sum::<i32>()
+    };
+    prop_assert_eq!(v.cmp_along(&p1, &p2), dot(&p1).cmp(&dot(&p2)));
+  }
+
+  #[proptest]
+  fn cmp_along_prop_3d(v: [i8; 3], p1: [i8; 3], p2: [i8; 3]) {
+    let dot = |p: &[i8; 3]| {
+      (0..3)
+        .map(|i| i32::from(v[i]) * i32::from(p[i]))
+        .sum::<i32>()
+    };
+    let wide = |p: [i8; 3]| p.map(i32::from);
+    prop_assert_eq!(
+      Vector(wide(v)).cmp_along(&Point::new(wide(p1)), &Point::new(wide(p2))),
+      dot(&p1).cmp(&dot(&p2))
+    );
+  }
+
   #[proptest]
   fn cmp_along_prop_x(p1: Point<i8, 2>, p2: Point<i8, 2>) {
     let v = Vector([1, 0]);
@@ -30,6 +30,12 @@ pub enum Error {
   ConvexViolation,
   ClockWiseViolation,
   CoLinearViolation,
+  /// A hole is not strictly inside the boundary or touches another ring.
+  InvalidHole,
+  /// A diagonal connects adjacent vertices or leaves the interior.
+  InvalidDiagonal,
+  /// An edge is not part of the polygon or a point doesn't lie on it.
+  InvalidEdge,
 }
 
 impl std::fmt::Display for Error {
@@ -44,6 +50,9 @@ impl std::fmt::Display for Error {
         f,
         "Two or more points are colinear and no valid solution exists"
       ),
+      Error::InvalidHole => write!(f, "Hole is not strictly inside the polygon"),
+      Error::InvalidDiagonal => write!(f, "Diagonal is not strictly inside the polygon"),
+      Error::InvalidEdge => write!(f, "Point is not on a polygon edge"),
     }
   }
 }
@@ -103,6 +112,57 @@ pub trait PolygonScalar:
   fn cmp_slope(p: &[Self; 2], q: &[Self; 2], r: &[Self; 2]) -> std::cmp::Ordering;
   fn cmp_vector_slope(p: &[Self; 2], q: &[Self; 2], r: &[Self; 2]) -> std::cmp::Ordering;
   fn cmp_perp_vector_slope(p: &[Self; 2], q: &[Self; 2], r: &[Self; 2]) -> std::cmp::Ordering;
+  /// Sign of the determinant of the vectors `q-p`, `r-p` and `s-p`.
+  ///
+  /// The default implementation computes the determinant with the arithmetic
+  /// of `Self` and is only exact if that arithmetic doesn't round or
+  /// overflow. The implementations in this crate are exact.
+  fn cmp_orient3d(
+    p: &[Self; 3],
+    q: &[Self; 3],
+    r: &[Self; 3],
+    s: &[Self; 3],
+  ) -> std::cmp::Ordering {
+    let diff = |q: &[Self; 3]| [0, 1, 2].map(|i| q[i].clone() - p[i].clone());
+    let [ux, uy, uz] = diff(q);
+    let [vx, vy, vz] = diff(r);
+    let [wx, wy, wz] = diff(s);
+    let det = ux * (vy.clone() * wz.clone() - vz.clone() * wy.clone())
+      - uy * (vx.clone() * wz - vz * wx.clone())
+      + uz * (vx * wy - vy * wx);
+    det.total_cmp(&Self::from_constant(0))
+  }
+  /// Sign of the in-circle determinant. For `a`, `b` and `c` in
+  /// counter-clockwise order, the result is `Greater` if `d` lies inside the
+  /// circle through `a`, `b` and `c`, `Less` if it lies outside and `Equal` if
+  /// it lies on the circle.
+  ///
+  /// The default implementation computes the determinant with the arithmetic
+  /// of `Self` and is only exact if that arithmetic doesn't round or
+  /// overflow. The implementations in this crate are exact.
+  fn incircle(a: &[Self; 2], b: &[Self; 2], c: &[Self; 2], d: &[Self; 2]) -> std::cmp::Ordering {
+    let lift = |q: &[Self; 2]| {
+      let dx = q[0].clone() - d[0].clone();
+      let dy = q[1].clone() - d[1].clone();
+      let dist = dx.clone() * dx.clone() + dy.clone() * dy.clone();
+      [dx, dy, dist]
+    };
+    let [ax, ay, az] = lift(a);
+    let [bx, by, bz] = lift(b);
+    let [cx, cy, cz] = lift(c);
+    let det = ax * (by.clone() * cz.clone() - bz.clone() * cy.clone())
+      - ay * (bx.clone() * cz - bz * cx.clone())
+      + az * (bx * cy - by * cx);
+    det.total_cmp(&Self::from_constant(0))
+  }
+}
+
+/// Exact conversion to a rational number.
+pub trait ToRational {
+  /// # Panics
+  ///
+  /// Panics if the value is NaN or infinite.
+  fn to_rational(&self) -> num::BigRational;
 }
 
 macro_rules! fixed_precision {
@@ -113,10 +173,41 @@ macro_rules! fixed_precision {
       }
     }
 
+    impl ToRational for $ty {
+      fn to_rational(&self) -> num::BigRational {
+        num::BigRational::from_integer(num::BigInt::from(*self))
+      }
+    }
+
     impl PolygonScalar for $ty {
       fn from_constant(val: i8) -> Self {
         val as $ty
       }
+      fn incircle(
+        a: &[Self; 2],
+        b: &[Self; 2],
+        c: &[Self; 2],
+        d: &[Self; 2],
+      ) -> std::cmp::Ordering {
+        rational_incircle(a, b, c, d)
+      }
+      fn cmp_orient3d(
+        p: &[Self; 3],
+        q: &[Self; 3],
+        r: &[Self; 3],
+        s: &[Self; 3],
+      ) -> std::cmp::Ordering {
+        // The determinant of 33 bit differences fits in an i128.
+        if std::mem::size_of::<$ty>() > 4 {
+          return rational_orient3d(p, q, r, s);
+        }
+        let diff = |q: &[Self; 3]| [0, 1, 2].map(|i| q[i] as i128 - p[i] as i128);
+        let [ux, uy, uz] = diff(q);
+        let [vx, vy, vz] = diff(r);
+        let [wx, wy, wz] = diff(s);
+        let det = ux * (vy * wz - vz * wy) - uy * (vx * wz - vz * wx) + uz * (vx * wy - vy * wx);
+        det.cmp(&0)
+      }
       fn cmp_dist(p: &[Self; 2], q: &[Self; 2], r: &[Self; 2]) -> std::cmp::Ordering {
         fn diff(a: $ty, b: $ty) -> $ulong {
           if b > a {
@@ -243,10 +334,22 @@ macro_rules! arbitrary_precision {
         }
       }
 
+      impl ToRational for $ty {
+        fn to_rational(&self) -> num::BigRational {
+          num::BigRational::from(self.clone())
+        }
+      }
+
       impl PolygonScalar for $ty {
       fn from_constant(val: i8) -> Self {
         <$ty>::from_i8(val).unwrap()
       }
+      fn cmp_orient3d(p: &[Self; 3], q: &[Self; 3], r: &[Self; 3], s: &[Self; 3]) -> std::cmp::Ordering {
+        rational_orient3d(p, q, r, s)
+      }
+      fn incircle(a: &[Self; 2], b: &[Self; 2], c: &[Self; 2], d: &[Self; 2]) -> std::cmp::Ordering {
+        rational_incircle(a, b, c, d)
+      }
       fn cmp_dist(p: &[Self; 2], q: &[Self; 2], r: &[Self; 2]) -> std::cmp::Ordering {
         let pq_x = &p[0] - &q[0];
         let pq_y = &p[1] - &q[1];
@@ -289,17 +392,27 @@ macro_rules! wrapped_floating_precision {
         }
       }
 
+      impl ToRational for $ty {
+        fn to_rational(&self) -> num::BigRational {
+          float_to_rational(self.into_inner())
+        }
+      }
+
       impl PolygonScalar for $ty {
       fn from_constant(val: i8) -> Self {
         <$ty>::from_i8(val).unwrap()
       }
-      // FIXME: Use `geometry_predicates` to speed up calculation. Right now we're
-      // roughly 100x slower than necessary.
+      fn cmp_orient3d(p: &[Self; 3], q: &[Self; 3], r: &[Self; 3], s: &[Self; 3]) -> std::cmp::Ordering {
+        rational_orient3d(p, q, r, s)
+      }
+      fn incircle(a: &[Self; 2], b: &[Self; 2], c: &[Self; 2], d: &[Self; 2]) -> std::cmp::Ordering {
+        rational_incircle(a, b, c, d)
+      }
       fn cmp_dist(p: &[Self; 2], q: &[Self; 2], r: &[Self; 2]) -> std::cmp::Ordering {
-        PolygonScalar::cmp_dist(
-          &[float_to_rational(p[0].into_inner()), float_to_rational(p[1].into_inner())],
-          &[float_to_rational(q[0].into_inner()), float_to_rational(q[1].into_inner())],
-          &[float_to_rational(r[0].into_inner()), float_to_rational(r[1].into_inner())],
+        float_cmp_dist(
+          [p[0].into_inner() as f64, p[1].into_inner() as f64],
+          [q[0].into_inner() as f64, q[1].into_inner() as f64],
+          [r[0].into_inner() as f64, r[1].into_inner() as f64],
         )
       }
 
@@ -351,17 +464,49 @@ macro_rules! floating_precision {
         }
       }
 
+      impl ToRational for $ty {
+        fn to_rational(&self) -> num::BigRational {
+          float_to_rational(*self)
+        }
+      }
+
       impl PolygonScalar for $ty {
       fn from_constant(val: i8) -> Self {
         <$ty>::from_i8(val).unwrap()
       }
-      // FIXME: Use `geometry_predicates` to speed up calculation. Right now we're
-      // roughly 100x slower than necessary.
+      // Exact orientation using the adaptive predicates of `geometry_predicates`.
+      fn cmp_orient3d(p: &[Self; 3], q: &[Self; 3], r: &[Self; 3], s: &[Self; 3]) -> std::cmp::Ordering {
+        // geometry_predicates::orient3d is positive when 's' lies below the
+        // plane through 'p', 'q' and 'r'. That is the opposite sign of our
+        // determinant.
+        let orient = geometry_predicates::predicates::orient3d(
+          [p[0] as f64, p[1] as f64, p[2] as f64],
+          [q[0] as f64, q[1] as f64, q[2] as f64],
+          [r[0] as f64, r[1] as f64, r[2] as f64],
+          [s[0] as f64, s[1] as f64, s[2] as f64],
+        );
+        if orient < 0.0 {
+          Ordering::Greater
+        } else if orient > 0.0 {
+          Ordering::Less
+        } else {
+          Ordering::Equal
+        }
+      }
+      fn incircle(a: &[Self; 2], b: &[Self; 2], c: &[Self; 2], d: &[Self; 2]) -> std::cmp::Ordering {
+        let incircle = geometry_predicates::predicates::incircle(
+          [a[0] as f64, a[1] as f64],
+          [b[0] as f64, b[1] as f64],
+          [c[0] as f64, c[1] as f64],
+          [d[0] as f64, d[1] as f64],
+        );
+        incircle.partial_cmp(&0.0).unwrap()
+      }
       fn cmp_dist(p: &[Self; 2], q: &[Self; 2], r: &[Self; 2]) -> std::cmp::Ordering {
-        PolygonScalar::cmp_dist(
-          &[float_to_rational(p[0]), float_to_rational(p[1])],
-          &[float_to_rational(q[0]), float_to_rational(q[1])],
-          &[float_to_rational(r[0]), float_to_rational(r[1])],
+        float_cmp_dist(
+          [p[0] as f64, p[1] as f64],
+          [q[0] as f64, q[1] as f64],
+          [r[0] as f64, r[1] as f64],
         )
       }
 
@@ -425,11 +570,30 @@ impl TotalOrd for rug::Integer {
   }
 }
 
+#[cfg(feature = "rug")]
+impl ToRational for rug::Integer {
+  fn to_rational(&self) -> num::BigRational {
+    let int: num::BigInt = self.to_string().parse().unwrap();
+    num::BigRational::from_integer(int)
+  }
+}
+
 #[cfg(feature = "rug")]
 impl PolygonScalar for rug::Integer {
   fn from_constant(val: i8) -> Self {
     rug::Integer::from(val)
   }
+  fn cmp_orient3d(
+    p: &[Self; 3],
+    q: &[Self; 3],
+    r: &[Self; 3],
+    s: &[Self; 3],
+  ) -> std::cmp::Ordering {
+    rational_orient3d(p, q, r, s)
+  }
+  fn incircle(a: &[Self; 2], b: &[Self; 2], c: &[Self; 2], d: &[Self; 2]) -> std::cmp::Ordering {
+    rational_incircle(a, b, c, d)
+  }
   fn cmp_dist(p: &[Self; 2], q: &[Self; 2], r: &[Self; 2]) -> std::cmp::Ordering {
     let [qx, qy] = q.clone();
     let [px, py] = p.clone();
@@ -467,6 +631,79 @@ impl PolygonScalar for rug::Integer {
   }
 }
 
+fn rational_orient3d<T: PolygonScalar + ToRational>(
+  p: &[T; 3],
+  q: &[T; 3],
+  r: &[T; 3],
+  s: &[T; 3],
+) -> Ordering {
+  let p = p.clone().map(|v| v.to_rational());
+  let diff = |q: &[T; 3]| {
+    let mut q = q.clone().map(|v| v.to_rational());
+    for (a, b) in q.iter_mut().zip(p.iter()) {
+      *a -= b;
+    }
+    q
+  };
+  let [ux, uy, uz] = diff(q);
+  let [vx, vy, vz] = diff(r);
+  let [wx, wy, wz] = diff(s);
+  let det =
+    &ux * (&vy * &wz - &vz * &wy) - &uy * (&vx * &wz - &vz * &wx) + &uz * (&vx * &wy - &vy * &wx);
+  det.cmp(&num::BigRational::zero())
+}
+
+fn rational_incircle<T: PolygonScalar + ToRational>(
+  a: &[T; 2],
+  b: &[T; 2],
+  c: &[T; 2],
+  d: &[T; 2],
+) -> Ordering {
+  let d = d.clone().map(|v| v.to_rational());
+  let lift = |q: &[T; 2]| {
+    let [x, y] = q.clone().map(|v| v.to_rational());
+    let dx = x - &d[0];
+    let dy = y - &d[1];
+    let dist = &dx * &dx + &dy * &dy;
+    [dx, dy, dist]
+  };
+  let [ax, ay, az] = lift(a);
+  let [bx, by, bz] = lift(b);
+  let [cx, cy, cz] = lift(c);
+  let det =
+    &ax * (&by * &cz - &bz * &cy) - &ay * (&bx * &cz - &bz * &cx) + &az * (&bx * &cy - &by * &cx);
+  det.cmp(&num::BigRational::zero())
+}
+
+// Compare the squared distances |pq| and |pr| in floating point and only fall
+// back to exact arithmetic when the difference is within the rounding error.
+// Squaring the coordinate differences and summing them accumulates at most
+// 4 ulps of relative error per distance, 8 ulps covers the final subtraction.
+fn float_cmp_dist(p: [f64; 2], q: [f64; 2], r: [f64; 2]) -> Ordering {
+  let [px, py] = p;
+  let [qx, qy] = q;
+  let [rx, ry] = r;
+  let pq = (px - qx) * (px - qx) + (py - qy) * (py - qy);
+  let pr = (px - rx) * (px - rx) + (py - ry) * (py - ry);
+  let magnitude = pq + pr;
+  let diff = pq - pr;
+  // Below this magnitude the squares may be subnormal and the relative error
+  // bound no longer holds.
+  let normal = magnitude >= f64::MIN_POSITIVE / f64::EPSILON;
+  if normal && magnitude.is_finite() && diff.abs() > 8.0 * f64::EPSILON * magnitude {
+    return if diff > 0.0 {
+      Ordering::Greater
+    } else {
+      Ordering::Less
+    };
+  }
+  PolygonScalar::cmp_dist(
+    &[float_to_rational(px), float_to_rational(py)],
+    &[float_to_rational(qx), float_to_rational(qy)],
+    &[float_to_rational(rx), float_to_rational(ry)],
+  )
+}
+
 fn float_to_rational(f: impl num::traits::float::FloatCore) -> num::BigRational {
   num::BigRational::from_float(f).expect("cannot convert NaN or infinite to exact precision number")
 }
@@ -1,5 +1,7 @@
 use std::cmp::Ordering;
 
+use num_traits::{CheckedMul, CheckedSub};
+
 use crate::data::Vector;
 use crate::PolygonScalar;
 
@@ -59,6 +61,97 @@ impl Orientation {
     }
   }
 
+  /// Like [`Orientation::new`] but only uses arithmetic in `T` itself.
+  /// Returns `None` if an intermediate value would overflow, in which case the
+  /// caller can promote the coordinates to a wider type and try again. A
+  /// `Some` result always agrees with [`Orientation::new`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::Orientation;
+  /// assert_eq!(
+  ///   Orientation::try_new_checked(&[0i8, 0], &[0, 1], &[-1, 2]),
+  ///   Some(Orientation::CounterClockWise)
+  /// );
+  /// assert_eq!(
+  ///   Orientation::try_new_checked(&[i8::MIN, 0], &[i8::MAX, 0], &[0, 1]),
+  ///   None
+  /// );
+  /// ```
+  pub fn try_new_checked<T>(p1: &[T; 2], p2: &[T; 2], p3: &[T; 2]) -> Option<Orientation>
+  where
+    T: PolygonScalar + CheckedSub + CheckedMul,
+  {
+    let ux = p2[0].checked_sub(&p1[0])?;
+    let uy = p2[1].checked_sub(&p1[1])?;
+    let vx = p3[0].checked_sub(&p1[0])?;
+    let vy = p3[1].checked_sub(&p1[1])?;
+    // Comparing the two products instead of subtracting them avoids one more
+    // overflow.
+    Some(
+      match ux.checked_mul(&vy)?.total_cmp(&uy.checked_mul(&vx)?) {
+        Ordering::Less => Orientation::ClockWise,
+        Ordering::Equal => Orientation::CoLinear,
+        Ordering::Greater => Orientation::CounterClockWise,
+      },
+    )
+  }
+
+  /// Orientations of many `f64` triples, identical to calling
+  /// [`Orientation::new`] on each of them.
+  ///
+  /// All determinants are first computed with plain floating point arithmetic
+  /// in a branch-free loop. Only the results that are too close to zero to be
+  /// trusted are recomputed with the robust predicate.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::Orientation;
+  /// let triples = [
+  ///   ([0.0, 0.0], [1.0, 0.0], [0.0, 1.0]),
+  ///   ([0.0, 0.0], [1.0, 1.0], [2.0, 2.0]),
+  ///   ([0.1, 0.1], [0.2, 0.2], [0.3, 0.3 + 1e-17]),
+  /// ];
+  /// let expected: Vec<Orientation> = triples
+  ///   .iter()
+  ///   .map(|(a, b, c)| Orientation::new(a, b, c))
+  ///   .collect();
+  /// assert_eq!(Orientation::orient2d_batch(&triples), expected);
+  /// ```
+  pub fn orient2d_batch(triples: &[([f64; 2], [f64; 2], [f64; 2])]) -> Vec<Orientation> {
+    // Error bound of the floating point determinant from Shewchuk's
+    // "Adaptive Precision Floating-Point Arithmetic and Fast Robust Geometric
+    // Predicates".
+    const EPSILON: f64 = f64::EPSILON / 2.0;
+    const ERRBOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+    let estimates: Vec<(f64, f64)> = triples
+      .iter()
+      .map(|(a, b, c)| {
+        let left = (a[0] - c[0]) * (b[1] - c[1]);
+        let right = (a[1] - c[1]) * (b[0] - c[0]);
+        (left - right, ERRBOUND * (left.abs() + right.abs()))
+      })
+      .collect();
+    triples
+      .iter()
+      .zip(estimates)
+      .map(|((a, b, c), (det, bound))| {
+        // NaN determinants and infinite bounds fail this test.
+        if bound.is_finite() && det.abs() >= bound {
+          match det.partial_cmp(&0.0) {
+            Some(Ordering::Greater) => Orientation::CounterClockWise,
+            Some(Ordering::Less) => Orientation::ClockWise,
+            _ => Orientation::CoLinear,
+          }
+        } else {
+          Orientation::new(a, b, c)
+        }
+      })
+      .collect()
+  }
+
   /// Locate `p2` in relation to the line determined by the point `p1` and the direction
   /// vector.
   ///
@@ -101,6 +194,38 @@ impl Orientation {
     }
   }
 
+  /// Determine on which side of the plane through `p1`, `p2` and `p3` the
+  /// point `p4` lies.
+  ///
+  /// The result is the sign of the determinant of the vectors `p2-p1`,
+  /// `p3-p1` and `p4-p1`. When `p1`, `p2` and `p3` appear counter-clockwise
+  /// seen from `p4`, the orientation is [`CounterClockWise`](Orientation::CounterClockWise).
+  /// Coplanar points are [`CoLinear`](Orientation::CoLinear).
+  ///
+  /// The computation is exact for every scalar type.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::Orientation;
+  /// let p1 = [0, 0, 0];
+  /// let p2 = [1, 0, 0];
+  /// let p3 = [0, 1, 0];
+  /// assert!(Orientation::orient3d(&p1, &p2, &p3, &[0, 0, 1]).is_ccw());
+  /// assert!(Orientation::orient3d(&p1, &p2, &p3, &[0, 0, -1]).is_cw());
+  /// assert!(Orientation::orient3d(&p1, &p2, &p3, &[5, 7, 0]).is_colinear());
+  /// ```
+  pub fn orient3d<T>(p1: &[T; 3], p2: &[T; 3], p3: &[T; 3], p4: &[T; 3]) -> Orientation
+  where
+    T: PolygonScalar,
+  {
+    match T::cmp_orient3d(p1, p2, p3, p4) {
+      Ordering::Less => Orientation::ClockWise,
+      Ordering::Equal => Orientation::CoLinear,
+      Ordering::Greater => Orientation::CounterClockWise,
+    }
+  }
+
   pub fn is_colinear(self) -> bool {
     matches!(self, Orientation::CoLinear)
   }
@@ -326,6 +451,31 @@ mod tests {
     }
   }
 
+  #[test]
+  fn try_new_checked_limits() {
+    let options = &[i8::MIN, i8::MAX, 0, -1, 1, -10, 10];
+    let mut checked = 0;
+    for [a, b, c, d, e, f] in crate::utils::permutations([options; 6]) {
+      let (p1, p2, p3) = ([a, b], [c, d], [e, f]);
+      if let Some(orientation) = Orientation::try_new_checked(&p1, &p2, &p3) {
+        assert_eq!(orientation, Orientation::new(&p1, &p2, &p3));
+        checked += 1;
+      }
+    }
+    assert!(checked > 0);
+    assert_eq!(
+      Orientation::try_new_checked(&[i8::MIN, i8::MIN], &[i8::MAX, i8::MAX], &[0, 0]),
+      None
+    );
+  }
+
+  #[proptest]
+  fn try_new_checked_prop(p1: [i8; 2], p2: [i8; 2], p3: [i8; 2]) {
+    if let Some(orientation) = Orientation::try_new_checked(&p1, &p2, &p3) {
+      prop_assert_eq!(orientation, Orientation::new(&p1, &p2, &p3));
+    }
+  }
+
   #[test]
   fn cmp_around_1() {
     use num_bigint::*;
@@ -371,6 +521,35 @@ mod tests {
     }
   }
 
+  #[proptest]
+  fn orient2d_batch_prop(triples: Vec<([f64; 2], [f64; 2], [f64; 2])>) {
+    let expected: Vec<Orientation> = triples
+      .iter()
+      .map(|(a, b, c)| Orientation::new(a, b, c))
+      .collect();
+    prop_assert_eq!(Orientation::orient2d_batch(&triples), expected);
+  }
+
+  #[proptest]
+  fn orient2d_batch_near_colinear_prop(
+    #[strategy(proptest::collection::vec((-1e3..1e3, -1e3..1e3, -1e3..1e3, -1e3..1e3, 0.0..1.0), 0..50))]
+    lines: Vec<(f64, f64, f64, f64, f64)>,
+  ) {
+    // Points interpolated between 'a' and 'b' are colinear up to rounding.
+    let triples: Vec<([f64; 2], [f64; 2], [f64; 2])> = lines
+      .into_iter()
+      .map(|(ax, ay, bx, by, t)| {
+        let c = [ax + t * (bx - ax), ay + t * (by - ay)];
+        ([ax, ay], [bx, by], c)
+      })
+      .collect();
+    let expected: Vec<Orientation> = triples
+      .iter()
+      .map(|(a, b, c)| Orientation::new(a, b, c))
+      .collect();
+    prop_assert_eq!(Orientation::orient2d_batch(&triples), expected);
+  }
+
   #[proptest]
   fn sos_rev_prop(a: u32, b: u32, c: u32) {
     if a != b && b != c && c != a {
@@ -380,4 +559,55 @@ mod tests {
       prop_assert_eq!(SoS::new(a, b, c), SoS::new(b, c, a));
     }
   }
+
+  #[test]
+  fn orient3d_coplanar() {
+    let p = [[0, 0, 0], [4, 0, 0], [0, 4, 0], [3, 3, 0]];
+    assert_eq!(
+      Orientation::orient3d(&p[0], &p[1], &p[2], &p[3]),
+      Orientation::CoLinear
+    );
+    let p = [[1, 2, 3], [2, 3, 4], [0, 0, 1], [3, 4, 5]];
+    assert_eq!(
+      Orientation::orient3d(&p[0], &p[1], &p[2], &p[3]),
+      Orientation::CoLinear
+    );
+  }
+
+  #[test]
+  fn orient3d_tetrahedron() {
+    let [a, b, c, d] = [[0, 0, 0], [1, 0, 0], [0, 1, 0], [0, 0, 1]];
+    assert!(Orientation::orient3d(&a, &b, &c, &d).is_ccw());
+    // Swapping two points flips the sign.
+    assert!(Orientation::orient3d(&b, &a, &c, &d).is_cw());
+    assert!(Orientation::orient3d(&a, &c, &b, &d).is_cw());
+    // Even permutations keep the sign.
+    assert!(Orientation::orient3d(&b, &c, &a, &d).is_ccw());
+  }
+
+  #[test]
+  fn orient3d_limit() {
+    let a = [i8::MIN, i8::MIN, i8::MIN];
+    let b = [i8::MAX, i8::MIN, i8::MIN];
+    let c = [i8::MIN, i8::MAX, i8::MIN];
+    let d = [i8::MIN, i8::MIN, i8::MAX];
+    assert!(Orientation::orient3d(&a, &b, &c, &d).is_ccw());
+  }
+
+  #[proptest]
+  fn orient3d_float_prop(a: [i8; 3], b: [i8; 3], c: [i8; 3], d: [i8; 3]) {
+    let float = |p: [i8; 3]| p.map(f64::from);
+    prop_assert_eq!(
+      Orientation::orient3d(&a, &b, &c, &d),
+      Orientation::orient3d(&float(a), &float(b), &float(c), &float(d))
+    );
+  }
+
+  #[proptest]
+  fn orient3d_swap_prop(a: [i8; 3], b: [i8; 3], c: [i8; 3], d: [i8; 3]) {
+    prop_assert_eq!(
+      Orientation::orient3d(&a, &b, &c, &d),
+      Orientation::orient3d(&a, &b, &d, &c).reverse()
+    );
+  }
 }
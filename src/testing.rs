@@ -542,3 +542,28 @@ where
       .prop_filter_map("Ensure CCW", |pts| Triangle::new(pts).ok())
   }
 }
+
+///////////////////////////////////////////////////////////////////////////////
+// Fixtures
+
+/// Counter-clockwise corners of the axis-aligned square with its lower-left
+/// corner at `(x, y)`.
+pub fn square_points<T: PolygonScalar>(x: T, y: T, size: T) -> Vec<Point<T>> {
+  let (x2, y2) = (x.clone() + size.clone(), y.clone() + size);
+  vec![
+    Point::new([x.clone(), y.clone()]),
+    Point::new([x2.clone(), y]),
+    Point::new([x2, y2.clone()]),
+    Point::new([x, y2]),
+  ]
+}
+
+/// [`square_points`] as a polygon.
+pub fn square<T: PolygonScalar>(x: T, y: T, size: T) -> Polygon<T> {
+  Polygon::new(square_points(x, y, size)).unwrap()
+}
+
+/// [`square_points`] as a convex polygon.
+pub fn square_convex<T: PolygonScalar>(x: T, y: T, size: T) -> PolygonConvex<T> {
+  PolygonConvex::new_unchecked(square(x, y, size))
+}
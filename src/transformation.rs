@@ -2,8 +2,10 @@
 use array_init::array_init;
 use num_traits::identities::One;
 use num_traits::identities::Zero;
+use num_traits::Float;
 use std::ops::Div;
 use std::ops::Mul;
+use std::ops::Neg;
 
 use crate::data::Point;
 use crate::data::Polygon;
@@ -61,6 +63,154 @@ where
     m[(N, N)] = T::one();
     Transform::new(m)
   }
+
+  /// Transformation that leaves every point where it is.
+  pub fn identity() -> Transform<T, N> {
+    Transform::uniform_scale(T::one())
+  }
+
+  /// Mirror along the given axis by negating that coordinate.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `axis` is not less than `N`.
+  pub fn reflect(axis: usize) -> Transform<T, N>
+  where
+    T: Neg<Output = T>,
+  {
+    assert!(axis < N, "axis {axis} out of range");
+    let mut m = Matrix::new(N + 1, N + 1);
+    for i in 0..=N {
+      m[(i, i)] = T::one();
+    }
+    m[(axis, axis)] = -T::one();
+    Transform::new(m)
+  }
+
+  /// Apply `self` and then `next`. Same as `next * self`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use rgeometry::data::*;
+  /// let t = Transform::translate(Vector([1, 0])).then(&Transform::uniform_scale(2));
+  /// assert_eq!(&t * Point::new([1, 1]), Point::new([4, 2]));
+  /// ```
+  pub fn then(&self, next: &Transform<T, N>) -> Transform<T, N> {
+    next * self
+  }
+}
+
+impl<T> Transform<T, 2>
+where
+  T: TransformScalar + Float,
+{
+  /// Counter-clockwise rotation around the origin by `angle` radians.
+  pub fn rotate(angle: T) -> Transform<T, 2> {
+    let (sin, cos) = angle.sin_cos();
+    let mut m = Matrix::new(3, 3);
+    m[(0, 0)] = cos;
+    m[(0, 1)] = -sin;
+    m[(1, 0)] = sin;
+    m[(1, 1)] = cos;
+    m[(2, 2)] = T::one();
+    Transform::new(m)
+  }
+}
+
+/// Compose transformations in the order they are applied.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rgeometry::data::*;
+/// // Move to the origin, then double in size.
+/// let t = TransformBuilder::new()
+///   .translate(Vector([-1, -1]))
+///   .uniform_scale(2)
+///   .build();
+/// assert_eq!(&t * Point::new([3, 2]), Point::new([4, 2]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TransformBuilder<T, const N: usize> {
+  transform: Transform<T, N>,
+}
+
+impl<T, const N: usize> TransformBuilder<T, N>
+where
+  T: TransformScalar,
+{
+  /// Start from the identity transformation.
+  pub fn new() -> TransformBuilder<T, N> {
+    TransformBuilder {
+      transform: Transform::identity(),
+    }
+  }
+
+  /// Apply `next` after the steps added so far.
+  #[must_use]
+  pub fn then(self, next: &Transform<T, N>) -> TransformBuilder<T, N> {
+    TransformBuilder {
+      transform: self.transform.then(next),
+    }
+  }
+
+  /// Translate by `vec`. See [`Transform::translate`].
+  #[must_use]
+  pub fn translate(self, vec: Vector<T, N>) -> TransformBuilder<T, N> {
+    self.then(&Transform::translate(vec))
+  }
+
+  /// Scale each axis by the matching coordinate of `vec`. See [`Transform::scale`].
+  #[must_use]
+  pub fn scale(self, vec: Vector<T, N>) -> TransformBuilder<T, N> {
+    self.then(&Transform::scale(vec))
+  }
+
+  /// Scale every axis by `v`. See [`Transform::uniform_scale`].
+  #[must_use]
+  pub fn uniform_scale(self, v: T) -> TransformBuilder<T, N> {
+    self.then(&Transform::uniform_scale(v))
+  }
+
+  /// Mirror along `axis`. See [`Transform::reflect`].
+  ///
+  /// # Panics
+  ///
+  /// Panics if `axis` is not less than `N`.
+  #[must_use]
+  pub fn reflect(self, axis: usize) -> TransformBuilder<T, N>
+  where
+    T: Neg<Output = T>,
+  {
+    self.then(&Transform::reflect(axis))
+  }
+
+  /// The composed transformation.
+  pub fn build(self) -> Transform<T, N> {
+    self.transform
+  }
+}
+
+impl<T> TransformBuilder<T, 2>
+where
+  T: TransformScalar + Float,
+{
+  /// Rotate counter-clockwise around the origin by `angle` radians. See
+  /// [`Transform::rotate`].
+  #[must_use]
+  pub fn rotate(self, angle: T) -> TransformBuilder<T, 2> {
+    self.then(&Transform::rotate(angle))
+  }
+}
+
+impl<T, const N: usize> Default for TransformBuilder<T, N>
+where
+  T: TransformScalar,
+{
+  fn default() -> Self {
+    TransformBuilder::new()
+  }
 }
 
 impl<T, const N: usize> Mul for Transform<T, N>
@@ -195,3 +345,54 @@ where
     (&self).mul(other)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use ordered_float::OrderedFloat;
+
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  #[proptest]
+  fn then_prop(
+    #[strategy(-100..100_i64)] dx: i64,
+    #[strategy(-100..100_i64)] dy: i64,
+    #[strategy(-10..10_i64)] factor: i64,
+    #[strategy(0..2_usize)] axis: usize,
+    #[strategy(-100..100_i64)] x: i64,
+    #[strategy(-100..100_i64)] y: i64,
+  ) {
+    let p = Point::new([x, y]);
+    let a = Transform::translate(Vector([dx, dy])).then(&Transform::reflect(axis));
+    let b = Transform::uniform_scale(factor);
+    prop_assert_eq!(&a.then(&b) * p, &b * (&a * p));
+  }
+
+  #[test]
+  fn builder_rotate() {
+    let t = TransformBuilder::new()
+      .translate(Vector([-1.0, 0.0]))
+      .rotate(std::f64::consts::FRAC_PI_2)
+      .build();
+    let [x, y] = (&t * Point::new([3.0, 0.0])).array;
+    assert!(x.abs() < 1e-12 && (y - 2.0).abs() < 1e-12);
+  }
+
+  #[test]
+  fn builder_normalize() {
+    let poly = Polygon::new(vec![
+      Point::new([0.0, 0.0]),
+      Point::new([4.0, 0.0]),
+      Point::new([4.0, 2.0]),
+      Point::new([0.0, 2.0]),
+    ])
+    .unwrap()
+    .map(OrderedFloat);
+    let t = TransformBuilder::new()
+      .translate(-Vector::from(poly.centroid()))
+      .uniform_scale(OrderedFloat(0.25))
+      .build();
+    assert!((&t * &poly).equals(&poly.normalize()));
+  }
+}